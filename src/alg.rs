@@ -3,11 +3,11 @@ use std::ops::{Neg, Add, Sub, Mul};
 use crate::rules::*;
 
 #[derive(Clone, Debug)]
-pub struct Poly<T: Reals> {
+pub struct Poly<T: Ring> {
     pub co: Vec<T>,
     pub le: usize,
 }
-impl<T: Reals> Poly<T> {
+impl<T: Ring> Poly<T> {
     pub fn new(co: Vec<T>) -> Self {
         let le: usize = co.len();
         Self { co, le }
@@ -22,6 +22,35 @@ impl<T: Reals> Poly<T> {
         }
         Self { co: result, le: self.le - 1 }
     }
+    pub fn eval(&self, input: T) -> T {
+        let mut total: T = T::ZERO;
+        let mut exponent: T = T::ONE;
+        for indx in 0..self.le {
+            total += self.co[indx] * exponent;
+            exponent *= input;
+        }
+        total
+    }
+    pub fn rootdiv(self, root: T) -> (Self, T) {
+        let mut running: T = T::ZERO;
+        let mut quotient: Vec<T> = Vec::new();
+        for subtract in 0..self.le {
+            running = running * root + self.co[self.le-subtract-1];
+            quotient.push(running);
+        }
+        quotient = vec_flip(quotient);
+        let remainder: T = quotient.remove(0);
+        (Self { co: quotient, le: self.le - 1 }, remainder)
+    }
+    pub fn trim(mut self) -> Self {
+        while self.le > 0 && self.co[self.le-1] == T::ZERO {
+            self.co.pop();
+            self.le -= 1;
+        }
+        self
+    }
+}
+impl<T: Field> Poly<T> {
     pub fn itg(&self, plus_c: T) -> Self {
         let mut result: Vec<T> = Vec::new();
         result.push(plus_c);
@@ -32,15 +61,40 @@ impl<T: Reals> Poly<T> {
         }
         Self{ co: result, le: self.le + 1 }
     }
-    pub fn eval(&self, input: T) -> T {
-        let mut total: T = T::ZERO;
-        let mut exponent: T = T::ONE;
-        for indx in 0..self.le {
-            total += self.co[indx] * exponent;
-            exponent *= input;
+    pub fn divrem(&self, rhs: &Self) -> (Self, Self) {
+        let divisor: Self = rhs.clone().trim();
+        if divisor.le == 0 { panic!("cannot divide by the zero polynomial") };
+        let mut remainder: Vec<T> = self.clone().trim().co;
+        if remainder.len() < divisor.le { return (Poly::new(Vec::new()), Poly::new(remainder)) };
+        let lead: T = divisor.co[divisor.le-1].inv();
+        let mut quotient: Vec<T> = vec![T::ZERO; remainder.len() - divisor.le + 1];
+        for shift in (0..quotient.len()).rev() {
+            let factor: T = remainder[shift + divisor.le - 1] * lead;
+            quotient[shift] = factor;
+            for indx in 0..divisor.le {
+                remainder[shift + indx] -= factor * divisor.co[indx];
+            }
         }
-        total
+        remainder.truncate(divisor.le - 1);
+        (Poly::new(quotient), Poly::new(remainder).trim())
+    }
+    pub fn monic(self) -> Self {
+        let trimmed: Self = self.trim();
+        if trimmed.le == 0 { return trimmed };
+        let lead: T = trimmed.co[trimmed.le-1].inv();
+        Poly::new(trimmed.co.iter().map(|term| *term * lead).collect())
+    }
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        let (mut p1, mut p2): (Self, Self) = (self.clone().trim(), rhs.clone().trim());
+        while p2.le > 0 {
+            let next: Self = p1.divrem(&p2).1;
+            p1 = p2;
+            p2 = next;
+        }
+        p1.monic()
     }
+}
+impl<T: Reals> Poly<T> {
     pub fn newton(&self, error: T) -> T {
         if self.le == 2 { return -self.co[0] / self.co[1] };
         let (mut s1, mut s2): (T, T) = (T::SEED, T::SEED + T::ONE);
@@ -58,17 +112,6 @@ impl<T: Reals> Poly<T> {
         }
         s1
     }
-    pub fn rootdiv(self, root: T) -> (Self, T) {
-        let mut running: T = T::ZERO;
-        let mut quotient: Vec<T> = Vec::new();
-        for subtract in 0..self.le {
-            running = running * root + self.co[self.le-subtract-1];
-            quotient.push(running);
-        }
-        quotient = vec_flip(quotient);
-        let remainder: T = quotient.remove(0);
-        (Self { co: quotient, le: self.le - 1 }, remainder)
-    }
     pub fn solve(self, error: T) -> Vec<T> {
         let mut running: Self = self;
         let mut sols: Vec<T> = Vec::new();
@@ -90,7 +133,7 @@ fn vec_flip<T: Copy>(original: Vec<T>) -> Vec<T> {
     result
 }
 
-impl<T: Reals> Neg for Poly<T> {
+impl<T: Ring> Neg for Poly<T> {
     type Output = Self;
     fn neg(self) -> Self {
         let mut result: Vec<T> = self.co;
@@ -98,7 +141,7 @@ impl<T: Reals> Neg for Poly<T> {
         Self { co: result, le: self.le }
     }
 }
-impl<T: Reals> Add for Poly<T> {
+impl<T: Ring> Add for Poly<T> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
         let mut result: Vec<T>;
@@ -116,27 +159,16 @@ impl<T: Reals> Add for Poly<T> {
         Poly::new(result)
     }
 }
-impl<T: Reals> Sub for Poly<T> {
+impl<T: Ring> Sub for Poly<T> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
-        let mut result: Vec<T>;
-        if self.le > rhs.le {
-            result = self.co.clone();
-            for term in 0..rhs.le {
-                result[term] -= rhs.co[term];
-            }
-        } else {
-            result = rhs.co.clone();
-            for term in 0..self.le {
-                result[term] -= self.co[term];
-            }
-        }
-        Poly::new(result)
+        self + -rhs
     }
 }
-impl<T: Reals> Mul for Poly<T> {
+impl<T: Ring> Mul for Poly<T> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
+        if self.le == 0 || rhs.le == 0 { return Poly::new(Vec::new()) };
         let mut product: Vec<T> = Vec::new();
         let newlen: usize = self.le + rhs.le - 1;
         for _ in 0..newlen { product.push(T::ZERO); }
@@ -0,0 +1,314 @@
+use std::ops::{Neg, Add, Sub, Mul};
+use std::cmp::{PartialEq, PartialOrd, Ordering};
+use crate::rules::*;
+use crate::alg::Poly;
+use crate::rat::Rat;
+use crate::nt::gcd;
+
+// The unique root of a squarefree `poly` strictly between `lo` and `hi`,
+// where neither endpoint is itself a root; `poly` is x - c for a rational c
+// and otherwise has no rational roots.
+#[derive(Clone, Debug)]
+pub struct Algebraic<R: RealArithmetic> {
+    pub poly: Poly<Rat<R>>,
+    pub lo: Rat<R>,
+    pub hi: Rat<R>,
+}
+type Bounds<R> = fn(&Algebraic<R>, &Algebraic<R>) -> (Rat<R>, Rat<R>);
+
+impl<R: RealArithmetic> Algebraic<R> {
+    pub fn from_rat(value: Rat<R>) -> Self {
+        Self {
+            poly: Poly::new(vec![-value, Rat::ONE]),
+            lo: value - Rat::ONE,
+            hi: value + Rat::ONE,
+        }
+    }
+    pub fn roots(poly: &Poly<Rat<R>>) -> Vec<Self> {
+        let poly: Poly<Rat<R>> = squarefree(poly);
+        if poly.le < 2 { return Vec::new() };
+        let isolated: Vec<(Rat<R>, Rat<R>)> = isolate(&poly);
+        isolated.iter().map(|&(lo, hi)| Self::reduced(&poly, lo, hi, &isolated)).collect()
+    }
+    pub fn refine(&mut self) {
+        let mid: Rat<R> = halfway(self.lo, self.hi);
+        let at_mid: Rat<R> = self.poly.eval(mid);
+        if at_mid == Rat::ZERO {
+            self.lo = halfway(self.lo, mid);
+            self.hi = halfway(mid, self.hi);
+        } else if (self.poly.eval(self.lo) < Rat::ZERO) != (at_mid < Rat::ZERO) {
+            self.hi = mid;
+        } else {
+            self.lo = mid;
+        }
+    }
+    pub fn approx(&self, width: Rat<R>) -> Rat<R> {
+        let mut running: Self = self.clone();
+        while running.hi - running.lo > width { running.refine(); }
+        halfway(running.lo, running.hi)
+    }
+    // rational roots are split off, so this is the true degree unless poly has two irrational factors
+    pub fn degree(&self) -> usize {
+        self.poly.le - 1
+    }
+    pub fn as_rat(&self) -> Option<Rat<R>> {
+        if self.poly.le == 2 { Some(-self.poly.co[0] / self.poly.co[1]) } else { None }
+    }
+    fn cmp_rat(&self, value: Rat<R>) -> Ordering {
+        if value <= self.lo { return Ordering::Greater };
+        if value >= self.hi { return Ordering::Less };
+        let at_value: Rat<R> = self.poly.eval(value);
+        if at_value == Rat::ZERO { return Ordering::Equal };
+        if (at_value < Rat::ZERO) == (self.poly.eval(self.lo) < Rat::ZERO) { Ordering::Greater } else { Ordering::Less }
+    }
+    fn is_zero(&self) -> bool {
+        self.poly.eval(Rat::ZERO) == Rat::ZERO && self.lo < Rat::ZERO && self.hi > Rat::ZERO
+    }
+    fn settle(poly: Poly<Rat<R>>, mut left: Self, mut right: Self, bounds: Bounds<R>) -> Self {
+        let poly: Poly<Rat<R>> = squarefree(&poly);
+        let seq: Vec<Poly<Rat<R>>> = sturm(&poly);
+        loop {
+            let (lo, hi): (Rat<R>, Rat<R>) = bounds(&left, &right);
+            if poly.eval(lo) != Rat::ZERO && poly.eval(hi) != Rat::ZERO && count(&seq, lo, hi) == 1 {
+                return Self::reduced(&poly, lo, hi, &isolate(&poly));
+            }
+            left.refine();
+            right.refine();
+        }
+    }
+    // the root of a squarefree poly in (lo, hi), with the rational roots found in isolated divided
+    // out of poly, or just x - c when the root is the rational c
+    fn reduced(poly: &Poly<Rat<R>>, lo: Rat<R>, hi: Rat<R>, isolated: &[(Rat<R>, Rat<R>)]) -> Self {
+        let mut rest: Poly<Rat<R>> = poly.clone();
+        for &(left, right) in isolated {
+            if let Some(value) = rational_root(poly, left, right) {
+                let linear: Poly<Rat<R>> = Poly::new(vec![-value, Rat::ONE]);
+                if lo < value && value < hi { return Self { poly: linear, lo, hi } };
+                rest = rest.divrem(&linear).0;
+            }
+        }
+        Self { poly: rest, lo, hi }
+    }
+}
+
+// disjoint intervals in increasing order, each holding one root of a squarefree poly and none at its ends
+fn isolate<R: RealArithmetic>(poly: &Poly<Rat<R>>) -> Vec<(Rat<R>, Rat<R>)> {
+    let seq: Vec<Poly<Rat<R>>> = sturm(poly);
+    let bound: Rat<R> = cauchy_bound(poly);
+    let mut pending: Vec<(Rat<R>, Rat<R>)> = vec![(-bound, bound)];
+    let mut result: Vec<(Rat<R>, Rat<R>)> = Vec::new();
+    while let Some((lo, hi)) = pending.pop() {
+        match count(&seq, lo, hi) {
+            0 => continue,
+            1 => { result.push((lo, hi)); continue },
+            _ => {},
+        }
+        let mid: Rat<R> = halfway(lo, hi);
+        if poly.eval(mid) == Rat::ZERO {
+            let mut width: Rat<R> = halfway(Rat::ZERO, mid - lo);
+            while count(&seq, mid - width, mid + width) != 1
+            || poly.eval(mid - width) == Rat::ZERO || poly.eval(mid + width) == Rat::ZERO {
+                width = halfway(Rat::ZERO, width);
+            }
+            result.push((mid - width, mid + width));
+            pending.push((lo, mid - width));
+            pending.push((mid + width, hi));
+        } else {
+            pending.push((lo, mid));
+            pending.push((mid, hi));
+        }
+    }
+    result.sort_by(|left, right| left.0.partial_cmp(&right.0).unwrap_or(Ordering::Equal));
+    result
+}
+// a rational root p/q of a polynomial with integer coefficients has q dividing the leading one,
+// so once the interval is narrower than 1/lead the only candidate is the next multiple of 1/lead
+fn rational_root<R: RealArithmetic>(poly: &Poly<Rat<R>>, lo: Rat<R>, hi: Rat<R>) -> Option<Rat<R>> {
+    let mut lead: R = R::ONE;
+    for term in &poly.co { lead = lead / gcd(lead, term.d) * term.d; }
+    let scale: Rat<R> = Rat::whole(lead);
+    let mut running: Algebraic<R> = Algebraic { poly: poly.clone(), lo, hi };
+    while (running.hi - running.lo) * scale >= Rat::ONE { running.refine(); }
+    let guess: Rat<R> = Rat::new((running.lo * scale).floor() + R::ONE, lead);
+    if guess < running.hi && poly.eval(guess) == Rat::ZERO { Some(guess) } else { None }
+}
+fn halfway<R: RealArithmetic>(lo: Rat<R>, hi: Rat<R>) -> Rat<R> {
+    (lo + hi) / Rat::whole(R::ONE + R::ONE)
+}
+fn squarefree<R: RealArithmetic>(poly: &Poly<Rat<R>>) -> Poly<Rat<R>> {
+    let poly: Poly<Rat<R>> = poly.clone().trim();
+    if poly.le < 2 { return poly };
+    let common: Poly<Rat<R>> = poly.gcd(&poly.dvt());
+    poly.divrem(&common).0.monic()
+}
+fn sturm<R: RealArithmetic>(poly: &Poly<Rat<R>>) -> Vec<Poly<Rat<R>>> {
+    let mut seq: Vec<Poly<Rat<R>>> = vec![poly.clone(), poly.dvt().trim()];
+    while seq[seq.len()-1].le > 0 {
+        let next: Poly<Rat<R>> = -seq[seq.len()-2].divrem(&seq[seq.len()-1]).1;
+        seq.push(next);
+    }
+    seq.pop();
+    seq
+}
+fn sign_changes<R: RealArithmetic>(seq: &[Poly<Rat<R>>], at: Rat<R>) -> usize {
+    let mut changes: usize = 0;
+    let mut last: Option<bool> = None;
+    for poly in seq {
+        let value: Rat<R> = poly.eval(at);
+        if value == Rat::ZERO { continue };
+        let negative: bool = value < Rat::ZERO;
+        if last.is_some_and(|sign| sign != negative) { changes += 1; }
+        last = Some(negative);
+    }
+    changes
+}
+fn count<R: RealArithmetic>(seq: &[Poly<Rat<R>>], lo: Rat<R>, hi: Rat<R>) -> usize {
+    sign_changes(seq, lo) - sign_changes(seq, hi)
+}
+fn cauchy_bound<R: RealArithmetic>(poly: &Poly<Rat<R>>) -> Rat<R> {
+    let lead: Rat<R> = poly.co[poly.le-1];
+    let mut largest: Rat<R> = Rat::ZERO;
+    for term in &poly.co[..poly.le-1] {
+        let mut ratio: Rat<R> = *term / lead;
+        if ratio < Rat::ZERO { ratio = -ratio; }
+        if ratio > largest { largest = ratio; }
+    }
+    largest + Rat::ONE
+}
+fn power<T: Ring>(base: T, exponent: usize) -> T {
+    let mut running: T = T::ONE;
+    for _ in 0..exponent { running *= base; }
+    running
+}
+fn resultant<T: Field>(f: &Poly<T>, g: &Poly<T>) -> T {
+    let (f, g): (Poly<T>, Poly<T>) = (f.clone().trim(), g.clone().trim());
+    if f.le == 0 || g.le == 0 { return T::ZERO };
+    if f.le == 1 { return power(f.co[0], g.le - 1) };
+    if g.le == 1 { return power(g.co[0], f.le - 1) };
+    let remainder: Poly<T> = f.divrem(&g).1;
+    if remainder.le == 0 { return T::ZERO };
+    let (df, dg, dr): (usize, usize, usize) = (f.le - 1, g.le - 1, remainder.le - 1);
+    let result: T = power(g.co[dg], df - dr) * resultant(&g, &remainder);
+    if df * dg % 2 == 1 { -result } else { result }
+}
+fn interpolate<T: Field>(points: &[(T, T)]) -> Poly<T> {
+    let mut diffs: Vec<T> = points.iter().map(|point| point.1).collect();
+    for level in 1..points.len() {
+        for indx in (level..points.len()).rev() {
+            diffs[indx] = (diffs[indx] - diffs[indx-1]) / (points[indx].0 - points[indx-level].0);
+        }
+    }
+    let mut result: Poly<T> = Poly::new(vec![diffs[points.len()-1]]);
+    for indx in (0..points.len()-1).rev() {
+        result = result * Poly::new(vec![-points[indx].0, T::ONE]) + Poly::new(vec![diffs[indx]]);
+    }
+    result.trim()
+}
+fn sample<R: RealArithmetic>(indx: usize) -> Rat<R> {
+    let mut running: Rat<R> = Rat::ZERO;
+    for _ in 0..indx.div_ceil(2) { running += Rat::ONE; }
+    if indx.is_multiple_of(2) { -running } else { running }
+}
+fn strip_zero_roots<R: RealArithmetic>(poly: &Poly<Rat<R>>) -> Poly<Rat<R>> {
+    let mut co: Vec<Rat<R>> = poly.co.clone();
+    while co.len() > 1 && co[0] == Rat::ZERO { co.remove(0); }
+    Poly::new(co)
+}
+
+impl<R: RealArithmetic> Neg for Algebraic<R> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let mut co: Vec<Rat<R>> = self.poly.co;
+        for term in co.iter_mut().skip(1).step_by(2) { *term = -*term; }
+        Self { poly: Poly::new(co), lo: -self.hi, hi: -self.lo }
+    }
+}
+impl<R: RealArithmetic> Add for Algebraic<R> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let degree: usize = self.degree() * rhs.degree();
+        let mut points: Vec<(Rat<R>, Rat<R>)> = Vec::new();
+        for indx in 0..=degree {
+            let x: Rat<R> = sample(indx);
+            let mut shifted: Poly<Rat<R>> = Poly::new(vec![rhs.poly.co[rhs.poly.le-1]]);
+            for term in rhs.poly.co[..rhs.poly.le-1].iter().rev() {
+                shifted = shifted * Poly::new(vec![x, -Rat::ONE]) + Poly::new(vec![*term]);
+            }
+            points.push((x, resultant(&self.poly, &shifted)));
+        }
+        Self::settle(interpolate(&points), self, rhs, |left, right| {
+            (left.lo + right.lo, left.hi + right.hi)
+        })
+    }
+}
+impl<R: RealArithmetic> Sub for Algebraic<R> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+impl<R: RealArithmetic> Mul for Algebraic<R> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() { return Self::from_rat(Rat::ZERO) };
+        let (mut left, mut right): (Self, Self) = (self, rhs);
+        while left.lo < Rat::ZERO && left.hi > Rat::ZERO { left.refine(); }
+        while right.lo < Rat::ZERO && right.hi > Rat::ZERO { right.refine(); }
+        left.poly = strip_zero_roots(&left.poly);
+        right.poly = strip_zero_roots(&right.poly);
+        let degree: usize = left.degree() * right.degree();
+        let mut points: Vec<(Rat<R>, Rat<R>)> = Vec::new();
+        for indx in 0..=degree {
+            let x: Rat<R> = sample(indx);
+            let mut scaled: Vec<Rat<R>> = Vec::new();
+            let mut running: Rat<R> = Rat::ONE;
+            for term in &right.poly.co {
+                scaled.push(*term * running);
+                running *= x;
+            }
+            scaled.reverse();
+            points.push((x, resultant(&left.poly, &Poly::new(scaled))));
+        }
+        Self::settle(interpolate(&points), left, right, |left, right| {
+            let corners: [Rat<R>; 4] = [
+                left.lo * right.lo, left.lo * right.hi,
+                left.hi * right.lo, left.hi * right.hi,
+            ];
+            let (mut lo, mut hi): (Rat<R>, Rat<R>) = (corners[0], corners[0]);
+            for corner in corners {
+                if corner < lo { lo = corner; }
+                if corner > hi { hi = corner; }
+            }
+            (lo, hi)
+        })
+    }
+}
+
+impl<R: RealArithmetic> PartialEq for Algebraic<R> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.partial_cmp(rhs) == Some(Ordering::Equal)
+    }
+}
+impl<R: RealArithmetic> PartialOrd for Algebraic<R> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if let Some(value) = rhs.as_rat() { return Some(self.cmp_rat(value)) };
+        if let Some(value) = self.as_rat() { return Some(rhs.cmp_rat(value).reverse()) };
+        let (mut left, mut right): (Self, Self) = (self.clone(), rhs.clone());
+        let common: Poly<Rat<R>> = left.poly.gcd(&right.poly);
+        let shared: Option<Vec<Poly<Rat<R>>>> = if common.le > 1 { Some(sturm(&common)) } else { None };
+        loop {
+            if left.hi <= right.lo { return Some(Ordering::Less) };
+            if right.hi <= left.lo { return Some(Ordering::Greater) };
+            if let Some(seq) = &shared {
+                let lo: Rat<R> = if left.lo < right.lo { left.lo } else { right.lo };
+                let hi: Rat<R> = if left.hi > right.hi { left.hi } else { right.hi };
+                if count(seq, left.lo, left.hi) == 1 && count(seq, right.lo, right.hi) == 1
+                && count(seq, lo, hi) == 1 {
+                    return Some(Ordering::Equal);
+                }
+            }
+            left.refine();
+            right.refine();
+        }
+    }
+}
@@ -3,8 +3,15 @@ use std::ops::{
     AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
 use std::cmp::{PartialEq, PartialOrd};
 use crate::rules::*;
+use crate::rat::Rat;
+use crate::nt::{exact_root, is_prime, factor, cornacchia};
 use std::fmt;
 
+/// ```compile_fail
+/// use basemath::{rules::Field, cc::Comp};
+/// fn field<T: Field>() {}
+/// field::<Comp<i32>>();
+/// ```
 #[derive(Clone, Copy, Debug)]
 pub struct Comp<R: RealArithmetic> {
     pub r: R,
@@ -38,6 +45,110 @@ pub type g32 = Comp<i32>;
 #[allow(non_camel_case_types)]
 pub type g64 = Comp<i64>;
 
+// Gaussian integer arithmetic, for g8 through g64
+impl<R: Integer> Comp<R> {
+    pub fn norm(self) -> R {
+        self.r * self.r + self.i * self.i
+    }
+    pub fn is_unit(self) -> bool {
+        self.norm() == R::ONE
+    }
+    pub fn units() -> [Self; 4] {
+        [Self::ONE, Self::nim(R::ONE), -Self::ONE, Self::nim(-R::ONE)]
+    }
+    // the associate in the first quadrant, with a positive real part
+    pub fn normalized(self) -> Self {
+        if self == Self::ZERO { return self };
+        let mut out: Self = self;
+        while !(out.r > R::ZERO && out.i >= R::ZERO) { out = Self { r: -out.i, i: out.r }; }
+        out
+    }
+    pub fn divrem(self, rhs: Self) -> (Self, Self) {
+        if rhs == Self::ZERO { panic!("division by the zero Gaussian integer") };
+        let divisor: R = rhs.norm();
+        let product: Self = self * rhs.conj();
+        let quotient: Self = Self {
+            r: Rat::new(product.r, divisor).round(),
+            i: Rat::new(product.i, divisor).round(),
+        };
+        (quotient, self - quotient * rhs)
+    }
+    pub fn divides(self, rhs: Self) -> bool {
+        if self == Self::ZERO { return rhs == Self::ZERO };
+        rhs.divrem(self).1 == Self::ZERO
+    }
+    pub fn gcd(self, rhs: Self) -> Self {
+        let (mut a, mut b): (Self, Self) = (self, rhs);
+        while b != Self::ZERO { (a, b) = (b, a.divrem(b).1); }
+        a.normalized()
+    }
+    // (g, s, t) with g = s*self + t*rhs and g normalized
+    pub fn ext_gcd(self, rhs: Self) -> (Self, Self, Self) {
+        let (mut a, mut b): (Self, Self) = (self, rhs);
+        let (mut s1, mut s2, mut t1, mut t2): (Self, Self, Self, Self) = (Self::ONE, Self::ZERO, Self::ZERO, Self::ONE);
+        while b != Self::ZERO {
+            let quotient: Self = a.divrem(b).0;
+            (a, b) = (b, a - quotient * b);
+            (s1, s2) = (s2, s1 - quotient * s2);
+            (t1, t2) = (t2, t1 - quotient * t2);
+        }
+        for unit in Self::units() {
+            if a * unit == a.normalized() { return (a * unit, s1 * unit, t1 * unit) };
+        }
+        (a, s1, t1)
+    }
+    pub fn is_gaussian_prime(self) -> bool {
+        let (r, i): (R, R) = (if self.r < R::ZERO { -self.r } else { self.r }, if self.i < R::ZERO { -self.i } else { self.i });
+        let four: R = R::ONE + R::ONE + R::ONE + R::ONE;
+        if r == R::ZERO || i == R::ZERO {
+            let side: R = if r == R::ZERO { i } else { r };
+            return is_prime(side) && side % four == four - R::ONE;
+        }
+        is_prime(self.norm())
+    }
+    // a unit and the normalized Gaussian primes, with multiplicity, whose product is self
+    pub fn factor(self) -> (Self, Vec<Self>) {
+        if self == Self::ZERO { panic!("zero has no factorization") };
+        let mut rest: Self = self;
+        let mut primes: Vec<Self> = Vec::new();
+        for (p, _) in factor(self.norm()) {
+            for candidate in gaussian_primes_over(p) {
+                while candidate.divides(rest) {
+                    rest = rest.divrem(candidate).0;
+                    primes.push(candidate);
+                }
+            }
+        }
+        (rest, primes)
+    }
+}
+
+impl<R: Integer> Comp<R> {
+    // the square root with nonnegative real part, when it is a Gaussian integer
+    pub fn exact_sqrt(self) -> Option<Self> {
+        let modulus: R = exact_root(self.r.checked_mul(self.r)?.checked_add(self.i.checked_mul(self.i)?)?, 2)?;
+        let two: R = R::ONE + R::ONE;
+        let r: R = exact_root(modulus.checked_add(self.r)? / two, 2)?;
+        let i: R = exact_root(modulus.checked_sub(self.r)? / two, 2)?;
+        let root: Self = Self { r, i: if self.i < R::ZERO { -i } else { i } };
+        if root * root == self { Some(root) } else { None }
+    }
+}
+
+// the normalized Gaussian primes dividing the rational prime p
+fn gaussian_primes_over<R: Integer>(p: R) -> Vec<Comp<R>> {
+    let four: R = R::ONE + R::ONE + R::ONE + R::ONE;
+    if p == R::ONE + R::ONE { return vec![Comp { r: R::ONE, i: R::ONE }] };
+    if p % four == four - R::ONE { return vec![Comp::nre(p)] };
+    match cornacchia(R::ONE, p) {
+        Some((a, b)) => {
+            let (small, large): (R, R) = if a < b { (a, b) } else { (b, a) };
+            vec![Comp { r: small, i: large }, Comp { r: large, i: small }]
+        },
+        None => panic!("{} is not a sum of two squares", p.to_i128()),
+    }
+}
+
 impl<R: RealArithmetic> Neg for Comp<R> {
     type Output = Self;
     fn neg(self) -> Self {
@@ -142,6 +253,8 @@ impl<R: RealArithmetic> Identity for Comp<R> {
     const ONE: Self = Self { r: R::ONE, i: R::ZERO };
     const SEED: Self = Self { r: R::ONE, i: R::ONE };
 } 
+impl<R: RealArithmetic> Ring for Comp<R> {}
+impl<R: RealArithmetic + Field> Field for Comp<R> {}
 impl<R: RealArithmetic> RealArithmetic for Comp<R> {}
 impl<R: RealArithmetic> Inverse for Comp<R> {
     fn inv(self) -> Self {
@@ -169,6 +282,7 @@ impl<R: RealArithmetic + UsefulReals> UsefulReals for Comp<R> {
     const PI: Self = Comp { r: R::PI, i: R::ZERO };
     const HALFPI: Self = Comp { r: R::HALFPI, i: R::ZERO };
     const QTRPI: Self = Comp { r: R::QTRPI, i: R::ZERO };
+    fn settle(self) -> Self { Comp { r: self.r.settle(), i: self.i.settle() } }
 }
 impl<R: RealArithmetic> MagSquare for Comp<R> {
     fn mag2(self) -> Self {
@@ -180,3 +294,141 @@ impl<R: Reals> Reals for Comp<R> {
     const UNDEF: Self = Self { r: R::UNDEF, i: R::UNDEF };
     const INFINITE: Self = Self { r: R::INFINITE, i: R::INFINITE };
 }
+
+// a + bω, where ω is a primitive cube root of unity, so ω² = -1 - ω
+#[derive(Clone, Copy, Debug)]
+pub struct Eisenstein<R: RealArithmetic> {
+    pub a: R,
+    pub b: R,
+}
+impl<R: RealArithmetic> Eisenstein<R> {
+    pub fn new(a: R, b: R) -> Self {
+        Self { a, b }
+    }
+    pub fn omega() -> Self {
+        Self { a: R::ZERO, b: R::ONE }
+    }
+    pub fn conj(self) -> Self {
+        Self { a: self.a - self.b, b: -self.b }
+    }
+    pub fn norm(self) -> R {
+        self.a * self.a - self.a * self.b + self.b * self.b
+    }
+}
+impl<R: Integer> Eisenstein<R> {
+    pub fn is_unit(self) -> bool {
+        self.norm() == R::ONE
+    }
+    pub fn units() -> [Self; 6] {
+        let omega: Self = Self::omega();
+        [Self::ONE, omega, omega * omega, -Self::ONE, -omega, -(omega * omega)]
+    }
+    // the associate with a > b >= 0
+    pub fn normalized(self) -> Self {
+        if self == Self::ZERO { return self };
+        let mut out: Self = self;
+        while !(out.a > out.b && out.b >= R::ZERO) { out = Self { a: out.a - out.b, b: out.a }; }
+        out
+    }
+    pub fn divrem(self, rhs: Self) -> (Self, Self) {
+        if rhs == Self::ZERO { panic!("division by the zero Eisenstein integer") };
+        let divisor: R = rhs.norm();
+        let product: Self = self * rhs.conj();
+        let quotient: Self = Self {
+            a: Rat::new(product.a, divisor).round(),
+            b: Rat::new(product.b, divisor).round(),
+        };
+        (quotient, self - quotient * rhs)
+    }
+    pub fn divides(self, rhs: Self) -> bool {
+        if self == Self::ZERO { return rhs == Self::ZERO };
+        rhs.divrem(self).1 == Self::ZERO
+    }
+    pub fn gcd(self, rhs: Self) -> Self {
+        let (mut x, mut y): (Self, Self) = (self, rhs);
+        while y != Self::ZERO { (x, y) = (y, x.divrem(y).1); }
+        x.normalized()
+    }
+    pub fn is_eisenstein_prime(self) -> bool {
+        let three: R = R::ONE + R::ONE + R::ONE;
+        let normal: Self = self.normalized();
+        if normal.b == R::ZERO {
+            return is_prime(normal.a) && normal.a % three == R::ONE + R::ONE;
+        }
+        is_prime(self.norm())
+    }
+}
+
+impl<R: RealArithmetic> PartialEq for Eisenstein<R> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.a == rhs.a && self.b == rhs.b
+    }
+}
+impl<R: RealArithmetic> Identity for Eisenstein<R> {
+    const ZERO: Self = Self { a: R::ZERO, b: R::ZERO };
+    const ONE: Self = Self { a: R::ONE, b: R::ZERO };
+}
+impl<R: RealArithmetic> Ring for Eisenstein<R> {}
+
+impl<R: RealArithmetic + fmt::Display> fmt::Display for Eisenstein<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.b < R::ZERO {
+            write!(f, "{}-{}ω", self.a, -self.b)
+        } else {
+            write!(f, "{}+{}ω", self.a, self.b)
+        }
+    }
+}
+impl<R: RealArithmetic + LaTeX> LaTeX for Eisenstein<R> {
+    fn latex(&self) -> String {
+        if self.b < R::ZERO {
+            format!("{}-{}\\omega", self.a, -self.b)
+        } else {
+            format!("{}+{}\\omega", self.a, self.b)
+        }
+    }
+}
+
+impl<R: RealArithmetic> Neg for Eisenstein<R> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { a: -self.a, b: -self.b }
+    }
+}
+impl<R: RealArithmetic> Add for Eisenstein<R> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self { a: self.a + rhs.a, b: self.b + rhs.b }
+    }
+}
+impl<R: RealArithmetic> Sub for Eisenstein<R> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self { a: self.a - rhs.a, b: self.b - rhs.b }
+    }
+}
+impl<R: RealArithmetic> Mul for Eisenstein<R> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let cross: R = self.b * rhs.b;
+        Self {
+            a: self.a * rhs.a - cross,
+            b: self.a * rhs.b + self.b * rhs.a - cross,
+        }
+    }
+}
+impl<R: RealArithmetic> AddAssign for Eisenstein<R> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<R: RealArithmetic> SubAssign for Eisenstein<R> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<R: RealArithmetic> MulAssign for Eisenstein<R> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
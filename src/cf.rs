@@ -0,0 +1,188 @@
+use crate::rules::*;
+use crate::rat::Rat;
+use crate::nt::isqrt;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuedFraction<R: RealArithmetic> {
+    pub terms: Vec<R>,
+}
+impl<R: RealArithmetic> ContinuedFraction<R> {
+    pub fn new(terms: Vec<R>) -> Self {
+        Self { terms }
+    }
+    pub fn from_rat(q: Rat<R>) -> Self {
+        let q: Rat<R> = Rat::new(q.n, q.d);
+        let (mut n, mut d): (R, R) = (q.n, q.d);
+        let mut terms: Vec<R> = Vec::new();
+        while d != R::ZERO {
+            let term: R = floor_div(n, d);
+            terms.push(term);
+            (n, d) = (d, n - term * d);
+        }
+        Self { terms }
+    }
+    pub fn from_real<X: Reals>(x: X, depth: usize) -> Self {
+        let mut terms: Vec<R> = Vec::new();
+        let mut rest: X = x;
+        for _ in 0..depth {
+            let term: X = floor(rest);
+            terms.push(whole(term));
+            if rest == term { break };
+            rest = (rest - term).inv();
+        }
+        Self { terms }
+    }
+    pub fn convergents(&self) -> Vec<Rat<R>> {
+        let (mut h1, mut h2, mut k1, mut k2): (R, R, R, R) = (R::ONE, R::ZERO, R::ZERO, R::ONE);
+        let mut result: Vec<Rat<R>> = Vec::new();
+        for term in &self.terms {
+            (h1, h2) = (*term * h1 + h2, h1);
+            (k1, k2) = (*term * k1 + k2, k1);
+            result.push(Rat::raw(h1, k1));
+        }
+        result
+    }
+    pub fn semiconvergents(&self) -> Vec<Rat<R>> {
+        let (mut h1, mut h2, mut k1, mut k2): (R, R, R, R) = (R::ONE, R::ZERO, R::ZERO, R::ONE);
+        let mut result: Vec<Rat<R>> = Vec::new();
+        for (indx, term) in self.terms.iter().enumerate() {
+            if indx > 0 {
+                let mut t: R = R::ONE;
+                while t < *term {
+                    result.push(Rat::raw(t * h1 + h2, t * k1 + k2));
+                    t += R::ONE;
+                }
+            }
+            (h1, h2) = (*term * h1 + h2, h1);
+            (k1, k2) = (*term * k1 + k2, k1);
+        }
+        result
+    }
+    pub fn value(&self) -> Rat<R> {
+        match self.convergents().pop() {
+            Some(last) => last,
+            None => Rat::ZERO,
+        }
+    }
+    pub fn best(&self, max_den: R) -> Rat<R> {
+        let (mut h1, mut h2, mut k1, mut k2): (R, R, R, R) = (R::ONE, R::ZERO, R::ZERO, R::ONE);
+        for (indx, term) in self.terms.iter().enumerate() {
+            if indx > 0 && *term * k1 + k2 > max_den {
+                let t: R = (max_den - k2) / k1;
+                let tail: Self = Self::new(self.terms[indx+1..].to_vec());
+                let closer: bool = t + t > *term || (t + t == *term
+                    && (tail.terms.is_empty() || Rat::new(k1, k2) < tail.value()));
+                if t > R::ZERO && closer { return Rat::raw(t * h1 + h2, t * k1 + k2) };
+                return Rat::raw(h1, k1);
+            }
+            (h1, h2) = (*term * h1 + h2, h1);
+            (k1, k2) = (*term * k1 + k2, k1);
+        }
+        Rat::raw(h1, k1)
+    }
+}
+impl<R: Integer> ContinuedFraction<R> {
+    // the integer part of √d followed by one full period, which ends with twice that part
+    pub fn of_sqrt(d: R) -> Self {
+        if d < R::ZERO { panic!("square root of a negative number") };
+        let two: R = R::ONE + R::ONE;
+        let root: R = isqrt(d);
+        let mut terms: Vec<R> = vec![root];
+        if root * root == d { return Self { terms } };
+        let (mut m, mut q, mut a): (R, R, R) = (R::ZERO, R::ONE, root);
+        while a != two * root {
+            m = q * a - m;
+            q = (d - m * m) / q;
+            a = (root + m) / q;
+            terms.push(a);
+        }
+        Self { terms }
+    }
+}
+
+pub fn best_rational_approx<X: Reals, R: RealArithmetic>(x: X, max_den: R) -> Rat<R> {
+    // NaN and ±∞ map to 0/0 and ±1/0, as Rat writes them
+    if x.partial_cmp(&X::ZERO).is_none() { return Rat::raw(R::ZERO, R::ZERO) };
+    if x == X::INFINITE { return Rat::raw(R::ONE, R::ZERO) };
+    if x == -X::INFINITE { return Rat::raw(-R::ONE, R::ZERO) };
+    let mut rest: X = x;
+    let first: X = floor(rest);
+    let (mut h1, mut h2, mut k1, mut k2): (R, R, R, R) = (whole(first), R::ONE, R::ONE, R::ZERO);
+    let mut term: X = first;
+    loop {
+        if rest == term { return Rat::raw(h1, k1) };
+        rest = (rest - term).inv();
+        term = floor(rest);
+        let t: R = (max_den - k2) / k1;
+        if term > whole::<R, X>(t) {
+            if t == R::ZERO { return Rat::raw(h1, k1) };
+            let (semi, conv): (Rat<R>, Rat<R>) = (Rat::raw(t * h1 + h2, t * k1 + k2), Rat::raw(h1, k1));
+            let distance = |q: Rat<R>| -> X { (whole::<R, X>(q.n) / whole::<R, X>(q.d) - x).mag2() };
+            return if distance(semi) < distance(conv) { semi } else { conv };
+        }
+        let step: R = whole(term);
+        (h1, h2) = (step * h1 + h2, h1);
+        (k1, k2) = (step * k1 + k2, k1);
+    }
+}
+
+fn floor_div<R: RealArithmetic>(n: R, d: R) -> R {
+    let quotient: R = n / d;
+    if n % d != R::ZERO && (n < R::ZERO) != (d < R::ZERO) { quotient - R::ONE } else { quotient }
+}
+fn floor<X: Reals>(x: X) -> X {
+    let truncated: X = x - x % X::ONE;
+    if truncated > x { truncated - X::ONE } else { truncated }
+}
+// converts a whole number between types using only their arithmetic
+fn whole<A: RealArithmetic, B: RealArithmetic>(a: A) -> B {
+    let negative: bool = a < A::ZERO;
+    let mut rest: A = if negative { -a } else { a };
+    let mut powers: Vec<(A, B)> = vec![(A::ONE, B::ONE)];
+    while powers[powers.len()-1].0 <= rest - powers[powers.len()-1].0 {
+        let (top_a, top_b): (A, B) = powers[powers.len()-1];
+        powers.push((top_a + top_a, top_b + top_b));
+    }
+    let mut result: B = B::ZERO;
+    for (power_a, power_b) in powers.into_iter().rev() {
+        if rest >= power_a {
+            rest -= power_a;
+            result += power_b;
+        }
+    }
+    if negative { -result } else { result }
+}
+
+impl<R: RealArithmetic> Rat<R> {
+    pub fn approx<X: Reals>(x: X, max_den: R) -> Self {
+        best_rational_approx(x, max_den)
+    }
+    pub fn limit(self, max_den: R) -> Self {
+        ContinuedFraction::from_rat(self).best(max_den)
+    }
+}
+
+impl<R: RealArithmetic + fmt::Display> fmt::Display for ContinuedFraction<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rest: Vec<String> = self.terms.iter().skip(1).map(|term| format!("{}", term)).collect();
+        match self.terms.first() {
+            None => write!(f, "[]"),
+            Some(first) if rest.is_empty() => write!(f, "[{}]", first),
+            Some(first) => write!(f, "[{}; {}]", first, rest.join(", ")),
+        }
+    }
+}
+impl<R: RealArithmetic + LaTeX> LaTeX for ContinuedFraction<R> {
+    fn latex(&self) -> String {
+        let mut result: String = String::new();
+        for (indx, term) in self.terms.iter().enumerate().rev() {
+            result = if indx == self.terms.len() - 1 {
+                term.latex()
+            } else {
+                format!("{} + \\cfrac{{1}}{{{}}}", term.latex(), result)
+            };
+        }
+        result
+    }
+}
@@ -0,0 +1,516 @@
+use crate::rules::*;
+use crate::rat::Rat;
+use crate::nt::gcd;
+
+// every function returns None when the result does not fit in the integer type
+pub fn factorial<N: Integer>(n: N) -> Option<N> {
+    if n < N::ZERO { panic!("factorial of a negative number") };
+    falling_factorial(n, n)
+}
+pub fn falling_factorial<N: Integer>(x: N, k: N) -> Option<N> {
+    if k < N::ZERO { panic!("negative number of factors") };
+    let (mut result, mut indx): (N, N) = (N::ONE, N::ZERO);
+    while indx < k {
+        result = result.checked_mul(x.checked_sub(indx)?)?;
+        if result == N::ZERO { return Some(result) };
+        indx += N::ONE;
+    }
+    Some(result)
+}
+pub fn rising_factorial<N: Integer>(x: N, k: N) -> Option<N> {
+    if k < N::ZERO { panic!("negative number of factors") };
+    let (mut result, mut indx): (N, N) = (N::ONE, N::ZERO);
+    while indx < k {
+        result = result.checked_mul(x.checked_add(indx)?)?;
+        if result == N::ZERO { return Some(result) };
+        indx += N::ONE;
+    }
+    Some(result)
+}
+// extended to negative n through C(n, k) = (-1)^k C(k - n - 1, k)
+pub fn binomial<N: Integer>(n: N, k: N) -> Option<N> {
+    if k < N::ZERO { return Some(N::ZERO) };
+    if n < N::ZERO {
+        let magnitude: N = binomial(k.checked_sub(n)? - N::ONE, k)?;
+        return Some(if k % (N::ONE + N::ONE) == N::ZERO { magnitude } else { -magnitude });
+    }
+    if k > n { return Some(N::ZERO) };
+    let k: N = if k > n - k { n - k } else { k };
+    let (mut result, mut indx): (N, N) = (N::ONE, N::ZERO);
+    while indx < k {
+        result = scale(result, n - indx, indx + N::ONE)?;
+        indx += N::ONE;
+    }
+    Some(result)
+}
+// the number of ways to split sum(parts) items into groups of the given sizes
+pub fn multinomial<N: Integer>(parts: &[N]) -> Option<N> {
+    let (mut total, mut result): (N, N) = (N::ZERO, N::ONE);
+    for part in parts {
+        if *part < N::ZERO { panic!("negative part in a multinomial") };
+        total = total.checked_add(*part)?;
+        result = result.checked_mul(binomial(total, *part)?)?;
+    }
+    Some(result)
+}
+pub fn catalan<N: Integer>(n: N) -> Option<N> {
+    if n < N::ZERO { panic!("catalan number of a negative index") };
+    let two: N = N::ONE + N::ONE;
+    let (mut result, mut indx): (N, N) = (N::ONE, N::ZERO);
+    while indx < n {
+        result = scale(result, two.checked_mul(two.checked_mul(indx)? + N::ONE)?, indx + two)?;
+        indx += N::ONE;
+    }
+    Some(result)
+}
+// the unsigned kind, counting permutations of n items with k cycles
+pub fn stirling_first<N: Integer>(n: N, k: N) -> Option<N> {
+    triangle(n, k, |row, _| row)
+}
+// the number of ways to split n items into k nonempty blocks
+pub fn stirling_second<N: Integer>(n: N, k: N) -> Option<N> {
+    triangle(n, k, |_, column| column)
+}
+pub fn bell<N: Integer>(n: N) -> Option<N> {
+    if n < N::ZERO { panic!("bell number of a negative index") };
+    let mut row: Vec<N> = vec![N::ONE];
+    let mut indx: N = N::ONE;
+    // each row of the Bell triangle starts with B(i) and ends with B(i + 1)
+    while indx < n {
+        let mut next: Vec<N> = vec![row[row.len() - 1]];
+        for entry in &row {
+            next.push(next[next.len() - 1].checked_add(*entry)?);
+        }
+        row = next;
+        indx += N::ONE;
+    }
+    Some(row[row.len() - 1])
+}
+// the number of ways to write n as an unordered sum of positive integers
+pub fn partitions<N: Integer>(n: N) -> Option<N> {
+    if n < N::ZERO { return Some(N::ZERO) };
+    let size: usize = n.to_i128() as usize;
+    let mut counts: Vec<u128> = vec![1];
+    // Euler's pentagonal number recurrence; its pairs of terms shrink with alternating signs, so every
+    // partial sum lies between 0 and p(m - 1) + p(m - 2), which fits in a u128 whenever p(m - 1) fits in an i128
+    for m in 1..=size {
+        let mut total: u128 = 0;
+        let mut k: usize = 1;
+        while k * (3 * k - 1) / 2 <= m {
+            let mut term: u128 = counts[m - k * (3 * k - 1) / 2];
+            if k * (3 * k + 1) / 2 <= m { term = term.checked_add(counts[m - k * (3 * k + 1) / 2])?; }
+            total = if k % 2 == 1 { total.checked_add(term)? } else { total - term };
+            k += 1;
+        }
+        counts.push(total);
+    }
+    N::from_i128(i128::try_from(counts[size]).ok()?)
+}
+// with B(1) = -1/2, so that the sum of C(n + 1, k) B(k) over k <= n vanishes for n > 0
+pub fn bernoulli<R: Integer>(n: R) -> Option<Rat<R>> {
+    if n < R::ZERO { panic!("bernoulli number of a negative index") };
+    if n > R::ONE && n % (R::ONE + R::ONE) == R::ONE { return Some(Rat::ZERO) };
+    // the Akiyama-Tanigawa algorithm, which yields B(1) = +1/2
+    let mut row: Vec<Rat<R>> = Vec::new();
+    let mut m: R = R::ZERO;
+    while m <= n {
+        row.push(Rat::new(R::ONE, m.checked_add(R::ONE)?));
+        let mut j: R = m;
+        while j > R::ZERO {
+            let indx: usize = j.to_i128() as usize;
+            row[indx - 1] = Rat::whole(j).checked_mul(row[indx - 1].checked_sub(row[indx])?)?;
+            j -= R::ONE;
+        }
+        m += R::ONE;
+    }
+    Some(if n == R::ONE { -row[0] } else { row[0] })
+}
+// the coefficients of sech, so E(2) = -1 and every odd one vanishes
+pub fn euler<N: Integer>(n: N) -> Option<N> {
+    if n < N::ZERO { panic!("euler number of a negative index") };
+    let size: usize = n.to_i128() as usize;
+    if size % 2 == 1 { return Some(N::ZERO) };
+    // the Seidel-Entringer triangle, whose last entry counts the alternating permutations
+    let mut row: Vec<N> = vec![N::ONE];
+    for indx in 1..=size {
+        let mut next: Vec<N> = vec![N::ZERO];
+        for column in 1..=indx {
+            next.push(next[column - 1].checked_add(row[indx - column])?);
+        }
+        row = next;
+    }
+    let zigzag: N = row[size];
+    Some(if size % 4 == 2 { -zigzag } else { zigzag })
+}
+pub fn harmonic<R: Integer>(n: R) -> Option<Rat<R>> {
+    harmonic_power(n, 1)
+}
+// the sum of 1/k^s for k from 1 to n
+pub fn harmonic_power<R: Integer>(n: R, s: u32) -> Option<Rat<R>> {
+    let (mut total, mut k): (Rat<R>, R) = (Rat::ZERO, R::ONE);
+    while k <= n {
+        let mut power: R = R::ONE;
+        for _ in 0..s { power = power.checked_mul(k)?; }
+        total = total.checked_add(Rat::new(R::ONE, power))?;
+        k += R::ONE;
+    }
+    Some(total)
+}
+// the rational c with zeta(2k) = c pi^(2k), from (-1)^(k+1) B(2k) (2 pi)^(2k) / (2 (2k)!)
+pub fn zeta_even<R: Integer>(k: R) -> Option<Rat<R>> {
+    if k <= R::ZERO { panic!("zeta_even needs a positive index") };
+    let two: R = R::ONE + R::ONE;
+    let n: R = two.checked_mul(k)?;
+    let mut result: Rat<R> = bernoulli(n)?.checked_div(Rat::whole(two))?;
+    let mut indx: R = R::ONE;
+    while indx <= n {
+        result = result.checked_mul(Rat::new(two, indx))?;
+        indx += R::ONE;
+    }
+    Some(if k % two == R::ZERO { -result } else { result })
+}
+
+impl<R: Integer> Rat<R> {
+    pub fn falling(self, k: R) -> Option<Self> {
+        if k < R::ZERO { panic!("negative number of factors") };
+        let (mut result, mut indx): (Self, R) = (Self::ONE, R::ZERO);
+        while indx < k {
+            result = result.checked_mul(self.checked_sub(Self::whole(indx))?)?;
+            indx += R::ONE;
+        }
+        Some(result)
+    }
+    pub fn rising(self, k: R) -> Option<Self> {
+        if k < R::ZERO { panic!("negative number of factors") };
+        let (mut result, mut indx): (Self, R) = (Self::ONE, R::ZERO);
+        while indx < k {
+            result = result.checked_mul(self.checked_add(Self::whole(indx))?)?;
+            indx += R::ONE;
+        }
+        Some(result)
+    }
+    // the generalized binomial coefficient, as in the series for (1 + x)^self
+    pub fn choose(self, k: R) -> Option<Self> {
+        if k < R::ZERO { return Some(Self::ZERO) };
+        let (mut result, mut indx): (Self, R) = (Self::ONE, R::ZERO);
+        while indx < k {
+            let step: Self = self.checked_sub(Self::whole(indx))?.checked_div(Self::whole(indx + R::ONE))?;
+            result = result.checked_mul(step)?;
+            indx += R::ONE;
+        }
+        Some(result)
+    }
+}
+
+// value * top / bottom for an exact quotient, cancelling first so only the result has to fit
+fn scale<N: Integer>(value: N, top: N, bottom: N) -> Option<N> {
+    let factor: N = gcd(value, bottom);
+    (value / factor).checked_mul(top / (bottom / factor))
+}
+// T(i + 1, j) = weight(i, j) T(i, j) + T(i, j - 1), starting from T(0, 0) = 1
+fn triangle<N: Integer>(n: N, k: N, weight: fn(N, N) -> N) -> Option<N> {
+    if n < N::ZERO { panic!("negative number of items") };
+    if k < N::ZERO || k > n { return Some(N::ZERO) };
+    let (n, k): (usize, usize) = (n.to_i128() as usize, k.to_i128() as usize);
+    let mut row: Vec<N> = vec![N::ZERO; k + 1];
+    row[0] = N::ONE;
+    for indx in 0..n {
+        // only the entries that still feed T(n, k) are kept, so nothing overflows needlessly
+        let lo: usize = (k + indx + 1).saturating_sub(n).max(1);
+        for column in (lo..=k.min(indx + 1)).rev() {
+            let step: N = weight(N::from_i128(indx as i128)?, N::from_i128(column as i128)?);
+            row[column] = step.checked_mul(row[column])?.checked_add(row[column - 1])?;
+        }
+        row[0] = N::ZERO;
+    }
+    Some(row[k])
+}
+
+// the orderings of items, lexicographic by position, so sorted items come out in sorted order
+#[derive(Clone, Debug)]
+pub struct Permutations<T: Clone> {
+    items: Vec<T>,
+    indices: Option<Vec<usize>>,
+}
+impl<T: Clone> Permutations<T> {
+    pub fn new(items: &[T]) -> Self {
+        Self { items: items.to_vec(), indices: Some((0..items.len()).collect()) }
+    }
+}
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Vec<T>> {
+        let current: Vec<usize> = self.indices.take()?;
+        let result: Vec<T> = current.iter().map(|indx| self.items[*indx].clone()).collect();
+        if let Some(pivot) = (1..current.len()).rev().find(|indx| current[indx - 1] < current[*indx]) {
+            let mut next: Vec<usize> = current;
+            let swap: usize = (pivot..next.len()).rev().find(|indx| next[*indx] > next[pivot - 1]).unwrap_or(pivot);
+            next.swap(pivot - 1, swap);
+            next[pivot..].reverse();
+            self.indices = Some(next);
+        }
+        Some(result)
+    }
+}
+pub fn permutation_rank(indices: &[usize]) -> usize {
+    let mut rank: usize = 0;
+    for (indx, value) in indices.iter().enumerate() {
+        let smaller: usize = indices[indx + 1..].iter().filter(|later| *later < value).count();
+        rank = rank * (indices.len() - indx) + smaller;
+    }
+    rank
+}
+pub fn permutation_unrank(n: usize, rank: usize) -> Vec<usize> {
+    let mut digits: Vec<usize> = vec![0; n];
+    let mut rest: usize = rank;
+    for indx in (0..n).rev() {
+        digits[indx] = rest % (n - indx);
+        rest /= n - indx;
+    }
+    if rest != 0 { panic!("rank out of range") };
+    let mut pool: Vec<usize> = (0..n).collect();
+    digits.iter().map(|digit| pool.remove(*digit)).collect()
+}
+
+// the orderings of items by Heap's algorithm, where each differs from the last by one swap
+#[derive(Clone, Debug)]
+pub struct HeapPermutations<T: Clone> {
+    items: Vec<T>,
+    counters: Vec<usize>,
+    indx: usize,
+    started: bool,
+}
+impl<T: Clone> HeapPermutations<T> {
+    pub fn new(items: &[T]) -> Self {
+        Self { items: items.to_vec(), counters: vec![0; items.len()], indx: 1, started: false }
+    }
+}
+impl<T: Clone> Iterator for HeapPermutations<T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+        while self.indx < self.items.len() {
+            if self.counters[self.indx] < self.indx {
+                let other: usize = if self.indx.is_multiple_of(2) { 0 } else { self.counters[self.indx] };
+                self.items.swap(other, self.indx);
+                self.counters[self.indx] += 1;
+                self.indx = 1;
+                return Some(self.items.clone());
+            }
+            self.counters[self.indx] = 0;
+            self.indx += 1;
+        }
+        None
+    }
+}
+
+// the k-element selections of items, lexicographic by position
+#[derive(Clone, Debug)]
+pub struct Combinations<T: Clone> {
+    items: Vec<T>,
+    indices: Option<Vec<usize>>,
+}
+impl<T: Clone> Combinations<T> {
+    pub fn new(items: &[T], k: usize) -> Self {
+        let indices: Option<Vec<usize>> = if k <= items.len() { Some((0..k).collect()) } else { None };
+        Self { items: items.to_vec(), indices }
+    }
+}
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Vec<T>> {
+        let current: Vec<usize> = self.indices.take()?;
+        let result: Vec<T> = current.iter().map(|indx| self.items[*indx].clone()).collect();
+        let (n, k): (usize, usize) = (self.items.len(), current.len());
+        if let Some(pivot) = (0..k).rev().find(|indx| current[*indx] < n - k + indx) {
+            let mut next: Vec<usize> = current;
+            next[pivot] += 1;
+            for indx in pivot + 1..k { next[indx] = next[indx - 1] + 1; }
+            self.indices = Some(next);
+        }
+        Some(result)
+    }
+}
+pub fn combination_rank(n: usize, indices: &[usize]) -> usize {
+    let k: usize = indices.len();
+    let mut later: usize = 0;
+    // counts the combinations that come after, which all start with a larger index somewhere
+    for (indx, value) in indices.iter().enumerate() {
+        later += count_combinations(n - 1 - value, k - indx);
+    }
+    count_combinations(n, k) - 1 - later
+}
+pub fn combination_unrank(n: usize, k: usize, rank: usize) -> Vec<usize> {
+    if rank >= count_combinations(n, k) { panic!("rank out of range") };
+    let mut result: Vec<usize> = Vec::new();
+    let (mut rest, mut value): (usize, usize) = (rank, 0);
+    while result.len() < k {
+        let starting_here: usize = count_combinations(n - 1 - value, k - 1 - result.len());
+        if rest < starting_here {
+            result.push(value);
+        } else {
+            rest -= starting_here;
+        }
+        value += 1;
+    }
+    result
+}
+
+// the subsets of items in binary order, where item i is present when bit i of the rank is set
+#[derive(Clone, Debug)]
+pub struct Subsets<T: Clone> {
+    items: Vec<T>,
+    mask: usize,
+}
+impl<T: Clone> Subsets<T> {
+    pub fn new(items: &[T]) -> Self {
+        if items.len() >= usize::BITS as usize { panic!("too many items for a power set") };
+        Self { items: items.to_vec(), mask: 0 }
+    }
+}
+impl<T: Clone> Iterator for Subsets<T> {
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.mask >> self.items.len() != 0 { return None };
+        let result: Vec<T> = subset_unrank(self.mask).iter().map(|indx| self.items[*indx].clone()).collect();
+        self.mask += 1;
+        Some(result)
+    }
+}
+pub fn subset_rank(indices: &[usize]) -> usize {
+    indices.iter().map(|indx| 1 << indx).sum()
+}
+pub fn subset_unrank(rank: usize) -> Vec<usize> {
+    (0..usize::BITS as usize).filter(|indx| rank >> indx & 1 == 1).collect()
+}
+
+// the partitions of n as nonincreasing parts, in reverse lexicographic order from [n]
+#[derive(Clone, Debug)]
+pub struct Partitions {
+    parts: Option<Vec<usize>>,
+}
+impl Partitions {
+    pub fn new(n: usize) -> Self {
+        Self { parts: Some(if n == 0 { Vec::new() } else { vec![n] }) }
+    }
+}
+impl Iterator for Partitions {
+    type Item = Vec<usize>;
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current: Vec<usize> = self.parts.take()?;
+        let mut next: Vec<usize> = current.clone();
+        let mut rest: usize = 0;
+        while next.last() == Some(&1) { next.pop(); rest += 1; }
+        if let Some(last) = next.pop() {
+            // lower the last part above one and refill behind it with parts no larger
+            rest += last;
+            while rest > 0 {
+                let part: usize = rest.min(last - 1);
+                next.push(part);
+                rest -= part;
+            }
+            self.parts = Some(next);
+        }
+        Some(current)
+    }
+}
+pub fn partition_rank(parts: &[usize]) -> usize {
+    let mut rest: usize = parts.iter().sum();
+    let table: Vec<Vec<usize>> = partition_table(rest);
+    let (mut rank, mut bound): (usize, usize) = (0, rest);
+    for part in parts {
+        for larger in part + 1..=bound.min(rest) {
+            rank += table[rest - larger][larger];
+        }
+        rest -= part;
+        bound = *part;
+    }
+    rank
+}
+pub fn partition_unrank(n: usize, rank: usize) -> Vec<usize> {
+    let table: Vec<Vec<usize>> = partition_table(n);
+    if rank >= table[n][n] { panic!("rank out of range") };
+    let mut result: Vec<usize> = Vec::new();
+    let (mut rest, mut remaining, mut bound): (usize, usize, usize) = (rank, n, n);
+    while remaining > 0 {
+        let mut part: usize = bound.min(remaining);
+        while rest >= table[remaining - part][part] {
+            rest -= table[remaining - part][part];
+            part -= 1;
+        }
+        result.push(part);
+        remaining -= part;
+        bound = part;
+    }
+    result
+}
+
+// the ordered ways to write n as a sum of positive parts; bit i of the rank
+// cuts between the (i + 1)-th and (i + 2)-th unit, so [n] comes first
+#[derive(Clone, Debug)]
+pub struct Compositions {
+    n: usize,
+    mask: usize,
+    count: usize,
+}
+impl Compositions {
+    pub fn new(n: usize) -> Self {
+        if n > usize::BITS as usize { panic!("too many compositions to enumerate") };
+        Self { n, mask: 0, count: if n == 0 { 1 } else { 1 << (n - 1) } }
+    }
+}
+impl Iterator for Compositions {
+    type Item = Vec<usize>;
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.mask >= self.count { return None };
+        let result: Vec<usize> = composition_unrank(self.n, self.mask);
+        self.mask += 1;
+        Some(result)
+    }
+}
+pub fn composition_rank(parts: &[usize]) -> usize {
+    let mut rank: usize = 0;
+    let mut position: usize = 0;
+    for part in &parts[..parts.len().saturating_sub(1)] {
+        position += part;
+        rank |= 1 << (position - 1);
+    }
+    rank
+}
+pub fn composition_unrank(n: usize, rank: usize) -> Vec<usize> {
+    if n == 0 { return Vec::new() };
+    if rank >> (n - 1) != 0 { panic!("rank out of range") };
+    let mut result: Vec<usize> = Vec::new();
+    let mut part: usize = 1;
+    for indx in 0..n - 1 {
+        if rank >> indx & 1 == 1 {
+            result.push(part);
+            part = 0;
+        }
+        part += 1;
+    }
+    result.push(part);
+    result
+}
+
+fn count_combinations(n: usize, k: usize) -> usize {
+    match binomial(n as i128, k as i128).map(usize::try_from) {
+        Some(Ok(count)) => count,
+        _ => panic!("too many combinations to rank"),
+    }
+}
+// table[n][m] counts the partitions of n into parts no larger than m
+fn partition_table(n: usize) -> Vec<Vec<usize>> {
+    let mut table: Vec<Vec<usize>> = vec![vec![1; n + 1]];
+    for total in 1..=n {
+        let mut row: Vec<usize> = vec![0; n + 1];
+        for largest in 1..=n {
+            row[largest] = row[largest - 1] + if largest <= total { table[total - largest][largest] } else { 0 };
+        }
+        table.push(row);
+    }
+    table
+}
@@ -0,0 +1,132 @@
+use crate::rules::*;
+use crate::rat::Rat;
+
+impl<R: RealArithmetic> Rat<R> {
+    pub fn mediant(self, rhs: Self) -> Self {
+        Self::raw(self.n + rhs.n, self.d + rhs.d)
+    }
+}
+
+// every reduced fraction in [0, 1] whose denominator is at most the order, in increasing order
+#[derive(Clone, Debug)]
+pub struct Farey<R: RealArithmetic> {
+    order: R,
+    current: Option<(Rat<R>, Rat<R>)>,
+}
+impl<R: RealArithmetic> Farey<R> {
+    pub fn new(order: R) -> Self {
+        if order < R::ONE { return Self { order, current: None } };
+        Self { order, current: Some((Rat::ZERO, Rat::raw(R::ONE, order))) }
+    }
+}
+impl<R: RealArithmetic> Iterator for Farey<R> {
+    type Item = Rat<R>;
+    fn next(&mut self) -> Option<Rat<R>> {
+        let (this, after): (Rat<R>, Rat<R>) = self.current?;
+        if this == Rat::ONE {
+            self.current = None;
+        } else {
+            let k: R = (self.order + this.d) / after.d;
+            self.current = Some((after, Rat::raw(k * after.n - this.n, k * after.d - this.d)));
+        }
+        Some(this)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Branch {
+    Left,
+    Right,
+}
+
+// a node of the Stern-Brocot tree, stored as the two fractions whose mediant it is
+#[derive(Clone, Copy, Debug)]
+pub struct SternBrocot<R: RealArithmetic> {
+    pub lo: Rat<R>,
+    pub hi: Rat<R>,
+}
+impl<R: RealArithmetic> SternBrocot<R> {
+    pub fn root() -> Self {
+        Self { lo: Rat::ZERO, hi: Rat::raw(R::ONE, R::ZERO) }
+    }
+    pub fn find(q: Rat<R>) -> Self {
+        let q: Rat<R> = Rat::new(q.n, q.d);
+        if q <= Rat::ZERO || q.d == R::ZERO { panic!("only positive rationals are in the Stern-Brocot tree") };
+        let mut node: Self = Self::root();
+        loop {
+            let value: Rat<R> = node.value();
+            if value == q { return node };
+            node = if q < value { node.left() } else { node.right() };
+        }
+    }
+    pub fn follow(path: &[Branch]) -> Self {
+        let mut node: Self = Self::root();
+        for step in path {
+            node = match step {
+                Branch::Left => node.left(),
+                Branch::Right => node.right(),
+            };
+        }
+        node
+    }
+    pub fn value(&self) -> Rat<R> {
+        self.lo.mediant(self.hi)
+    }
+    pub fn left(&self) -> Self {
+        Self { lo: self.lo, hi: self.value() }
+    }
+    pub fn right(&self) -> Self {
+        Self { lo: self.value(), hi: self.hi }
+    }
+    pub fn children(&self) -> (Self, Self) {
+        (self.left(), self.right())
+    }
+    pub fn parent(&self) -> Option<Self> {
+        let (lo, hi): (Rat<R>, Rat<R>) = (self.lo, self.hi);
+        if lo.n == R::ZERO && hi.d == R::ZERO { return None };
+        // the deeper bound is the parent itself, the other stays shared
+        if lo.n + lo.d > hi.n + hi.d {
+            Some(Self { lo: Rat::raw(lo.n - hi.n, lo.d - hi.d), hi })
+        } else {
+            Some(Self { lo, hi: Rat::raw(hi.n - lo.n, hi.d - lo.d) })
+        }
+    }
+    pub fn path(&self) -> Vec<Branch> {
+        let mut result: Vec<Branch> = Vec::new();
+        let mut node: Self = *self;
+        while let Some(parent) = node.parent() {
+            result.push(if parent.left().value() == node.value() { Branch::Left } else { Branch::Right });
+            node = parent;
+        }
+        result.reverse();
+        result
+    }
+    pub fn depth(&self) -> usize {
+        self.path().len()
+    }
+}
+
+// breadth-first through the Calkin-Wilf tree, so every positive rational appears once
+#[derive(Clone, Copy, Debug)]
+pub struct CalkinWilf<R: RealArithmetic> {
+    current: Rat<R>,
+}
+impl<R: RealArithmetic> CalkinWilf<R> {
+    pub fn new() -> Self {
+        Self { current: Rat::ONE }
+    }
+}
+impl<R: RealArithmetic> Default for CalkinWilf<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<R: RealArithmetic> Iterator for CalkinWilf<R> {
+    type Item = Rat<R>;
+    fn next(&mut self) -> Option<Rat<R>> {
+        let this: Rat<R> = self.current;
+        let whole: R = this.floor();
+        self.current = Rat::raw(this.d, whole * this.d + whole * this.d - this.n + this.d);
+        Some(this)
+    }
+}
@@ -0,0 +1,260 @@
+use std::ops::{
+    Neg, Add, Sub, Mul, Div,
+    AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::rules::*;
+use crate::alg::Poly;
+use crate::zmod::{Zmod, prime_modulus};
+use crate::nt::factor_u128;
+use std::fmt;
+
+// GF(P^N) as polynomials over Zmod<P> modulo the monic irreducible
+// x^N + m(x), where the coefficients of m are the base-P digits of M.
+// The AES field x^8 + x^4 + x^3 + x + 1 is Gf<2, 8, 0b00011011>.
+/// ```compile_fail
+/// use basemath::gf::Gf;
+/// let _ = Gf::<4, 2, 1>::is_field();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf<const P: u64, const N: usize, const M: u64> {
+    pub co: [Zmod<P>; N],
+}
+impl<const P: u64, const N: usize, const M: u64> Gf<P, N, M> {
+    pub fn new(co: [Zmod<P>; N]) -> Self {
+        Self { co }
+    }
+    // base-P digits as coefficients, where those past x^(N-1) are reduced by the modulus
+    pub fn from_int(n: u64) -> Self {
+        let mut digits: Vec<Zmod<P>> = Vec::new();
+        let mut running: u64 = n;
+        while running > 0 {
+            digits.push(Zmod::raw(running % P));
+            running /= P;
+        }
+        Self::from_poly(&Poly::new(digits))
+    }
+    pub fn to_int(self) -> u64 {
+        let mut total: u64 = 0;
+        for term in self.co.iter().rev() {
+            total = total * P + term.v;
+        }
+        total
+    }
+    pub fn from_poly(poly: &Poly<Zmod<P>>) -> Self {
+        let reduced: Poly<Zmod<P>> = poly.divrem(&Self::modulus()).1;
+        let mut co: [Zmod<P>; N] = [Zmod::ZERO; N];
+        co[..reduced.le].copy_from_slice(&reduced.co);
+        Self { co }
+    }
+    pub fn to_poly(self) -> Poly<Zmod<P>> {
+        Poly::new(self.co.to_vec()).trim()
+    }
+    pub fn modulus() -> Poly<Zmod<P>> {
+        let mut co: Vec<Zmod<P>> = Self::reduction().to_vec();
+        co.push(Zmod::ONE);
+        Poly::new(co)
+    }
+    pub fn order() -> u128 {
+        (P as u128).pow(N as u32)
+    }
+    pub fn is_field() -> bool {
+        const { assert!(prime_modulus(P), "GF(P^N) needs a prime P") };
+        let modulus: Poly<Zmod<P>> = Self::modulus();
+        let x: Poly<Zmod<P>> = Poly::new(vec![Zmod::ZERO, Zmod::ONE]);
+        let mut frob: Self = Self::from_poly(&x);
+        for _ in 0..N/2 {
+            frob = frob.frobenius();
+            if modulus.gcd(&(frob.to_poly() - x.clone())).le > 1 { return false };
+        }
+        true
+    }
+    pub fn pow(self, power: u128) -> Self {
+        let (mut base, mut power): (Self, u128) = (self, power);
+        let mut running: Self = Self::ONE;
+        while power > 0 {
+            if power & 1 == 1 { running *= base; }
+            base *= base;
+            power >>= 1;
+        }
+        running
+    }
+    pub fn frobenius(self) -> Self {
+        self.pow(P as u128)
+    }
+    pub fn conjugates(self) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::new();
+        let mut running: Self = self;
+        for _ in 0..N {
+            result.push(running);
+            running = running.frobenius();
+        }
+        result
+    }
+    pub fn trace(self) -> Zmod<P> {
+        let mut total: Self = Self::ZERO;
+        for conj in self.conjugates() { total += conj; }
+        total.co[0]
+    }
+    pub fn norm(self) -> Zmod<P> {
+        let mut total: Self = Self::ONE;
+        for conj in self.conjugates() { total *= conj; }
+        total.co[0]
+    }
+    pub fn mult_order(self) -> u128 {
+        if self == Self::ZERO { return 0 };
+        let mut order: u128 = Self::order() - 1;
+        for (prime, _) in factor_u128(order) {
+            while order.is_multiple_of(prime) && self.pow(order / prime) == Self::ONE {
+                order /= prime;
+            }
+        }
+        order
+    }
+    pub fn generator() -> Self {
+        let group: u128 = Self::order() - 1;
+        let primes: Vec<u128> = factor_u128(group).iter().map(|(prime, _)| *prime).collect();
+        for n in 1..Self::order() as u64 {
+            let candidate: Self = Self::from_int(n);
+            if primes.iter().all(|prime| candidate.pow(group / prime) != Self::ONE) {
+                return candidate;
+            }
+        }
+        panic!("no generator found, the modulus is not irreducible")
+    }
+    fn written(&self, latex: bool) -> String {
+        let mut terms: Vec<String> = Vec::new();
+        for (power, term) in self.co.iter().enumerate().rev() {
+            if term.v == 0 { continue };
+            let coefficient: String = if term.v == 1 && power > 0 { String::new() } else { term.v.to_string() };
+            terms.push(match (power, latex) {
+                (0, _) => coefficient,
+                (1, _) => format!("{}x", coefficient),
+                (_, false) => format!("{}x^{}", coefficient, power),
+                (_, true) => format!("{}x^{{{}}}", coefficient, power),
+            });
+        }
+        if terms.is_empty() { String::from("0") } else { terms.join("+") }
+    }
+    fn reduction() -> [Zmod<P>; N] {
+        const { assert!(prime_modulus(P), "GF(P^N) needs a prime P") };
+        let mut co: [Zmod<P>; N] = [Zmod::ZERO; N];
+        let mut running: u64 = M;
+        for term in co.iter_mut() {
+            *term = Zmod::raw(running % P);
+            running /= P;
+        }
+        co
+    }
+}
+
+fn poly_ext_gcd<const P: u64>(
+    p1: Poly<Zmod<P>>, p2: Poly<Zmod<P>>,
+) -> (Poly<Zmod<P>>, Poly<Zmod<P>>) {
+    let (mut r0, mut r1): (Poly<Zmod<P>>, Poly<Zmod<P>>) = (p1.trim(), p2.trim());
+    let (mut t0, mut t1): (Poly<Zmod<P>>, Poly<Zmod<P>>) = (Poly::new(Vec::new()), Poly::new(vec![Zmod::ONE]));
+    while r1.le > 0 {
+        let (quotient, remainder): (Poly<Zmod<P>>, Poly<Zmod<P>>) = r0.divrem(&r1);
+        let next: Poly<Zmod<P>> = (t0 - quotient * t1.clone()).trim();
+        (r0, r1) = (r1, remainder);
+        (t0, t1) = (t1, next);
+    }
+    (r0, t0)
+}
+
+impl<const P: u64, const N: usize, const M: u64> Identity for Gf<P, N, M> {
+    const ZERO: Self = Self { co: [Zmod::ZERO; N] };
+    const ONE: Self = {
+        let mut co: [Zmod<P>; N] = [Zmod::ZERO; N];
+        co[0] = Zmod::ONE;
+        Self { co }
+    };
+}
+impl<const P: u64, const N: usize, const M: u64> Inverse for Gf<P, N, M> {
+    fn inv(self) -> Self {
+        let (divisor, factor): (Poly<Zmod<P>>, Poly<Zmod<P>>) = poly_ext_gcd(Self::modulus(), self.to_poly());
+        if divisor.le != 1 { panic!("{} has no inverse in GF({}^{})", self, P, N) };
+        Self::from_poly(&(factor * Poly::new(vec![divisor.co[0].inv()])))
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> Ring for Gf<P, N, M> {}
+impl<const P: u64, const N: usize, const M: u64> Field for Gf<P, N, M> {}
+
+impl<const P: u64, const N: usize, const M: u64> fmt::Display for Gf<P, N, M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.written(false))
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> LaTeX for Gf<P, N, M> {
+    fn latex(&self) -> String {
+        self.written(true)
+    }
+}
+
+impl<const P: u64, const N: usize, const M: u64> Neg for Gf<P, N, M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let mut co: [Zmod<P>; N] = self.co;
+        for term in co.iter_mut() { *term = -*term; }
+        Self { co }
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> Add for Gf<P, N, M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut co: [Zmod<P>; N] = self.co;
+        for (term, other) in co.iter_mut().zip(rhs.co) { *term += other; }
+        Self { co }
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> Sub for Gf<P, N, M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> Mul for Gf<P, N, M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut product: Vec<Zmod<P>> = vec![Zmod::ZERO; 2*N - 1];
+        for left in 0..N {
+            for right in 0..N {
+                product[left+right] += self.co[left] * rhs.co[right];
+            }
+        }
+        let reduction: [Zmod<P>; N] = Self::reduction();
+        for top in (N..2*N - 1).rev() {
+            let factor: Zmod<P> = product[top];
+            for indx in 0..N {
+                product[top - N + indx] -= factor * reduction[indx];
+            }
+        }
+        let mut co: [Zmod<P>; N] = [Zmod::ZERO; N];
+        co.copy_from_slice(&product[..N]);
+        Self { co }
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> Div for Gf<P, N, M> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inv())
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> AddAssign for Gf<P, N, M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> SubAssign for Gf<P, N, M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> MulAssign for Gf<P, N, M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<const P: u64, const N: usize, const M: u64> DivAssign for Gf<P, N, M> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
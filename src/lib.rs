@@ -4,8 +4,19 @@ pub mod alg;
 pub mod rat;
 pub mod prim;
 pub mod lin;
+pub mod zmod;
+pub mod gf;
+pub mod padic;
+pub mod quad;
+pub mod algebraic;
+pub mod posit;
+pub mod cf;
+pub mod farey;
+pub mod units;
+pub mod nt;
+pub mod comb;
 #[allow(unused_imports)]
-use crate::{rules::*, cc::*, alg::*, rat::*, prim::*, lin::*};
+use crate::{rules::*, cc::*, alg::*, rat::*, prim::*, lin::*, zmod::*, gf::*, padic::*, quad::*, algebraic::*, posit::*, cf::*, farey::*, units::*, nt::*, comb::*};
 
 #[cfg(test)]
 mod test {
@@ -15,4 +26,626 @@ mod test {
       let third: Rat<i32> = Rat::new(2, 6);
       println!("{}", third.latex());
    }
-}
\ No newline at end of file
+   #[test]
+   fn residues() {
+      let three: Zmod<7> = Zmod::new(3);
+      assert_eq!(three.inv(), Zmod::new(5));
+      assert_eq!(Zmod::<7>::new(-1), Zmod::new(6));
+      assert_eq!(three.latex(), "3 \\pmod{7}");
+      let curve: Poly<Zmod<7>> = Poly::new(vec![Zmod::new(1), Zmod::new(0), Zmod::new(1)]);
+      assert_eq!(curve.eval(three), Zmod::new(3));
+      assert_eq!((curve.clone() * curve).eval(three), Zmod::new(2));
+      assert_eq!(Zmod::<8>::new(2).try_inv(), None);
+      assert_eq!(Zmod::<7>::raw(10), Zmod::new(3));
+      assert!(Zmod::<7>::is_field());
+      assert!(!Zmod::<8>::is_field());
+   }
+   #[test]
+   fn galois() {
+      type Aes = Gf<2, 8, 0b00011011>;
+      assert!(Aes::is_field());
+      assert_eq!((Aes::from_int(0x53) * Aes::from_int(0xca)).to_int(), 0x01);
+      assert_eq!(Aes::from_int(0x53).inv().to_int(), 0xca);
+      assert_eq!(Aes::generator().to_int(), 0x03);
+      assert_eq!(Aes::from_int(0x02).mult_order(), 51);
+      assert_eq!(Aes::from_int(0x100).to_int(), 0x1b);
+      assert_eq!(Aes::from_int(0x153), Aes::from_int(0x53) + Aes::from_int(0x1b));
+      type Nine = Gf<3, 2, 1>;
+      let x: Nine = Nine::from_int(3);
+      assert_eq!(x.to_string(), "x");
+      assert_eq!((x * x).latex(), "2");
+      assert_eq!(x.norm(), Zmod::new(1));
+      assert_eq!(x.trace(), Zmod::new(0));
+      assert!(!Gf::<3, 2, 2>::is_field());
+      assert_eq!(Nine::from_int(9).to_int(), 2);
+   }
+   #[test]
+   fn padics() {
+      type Seven = PAdic<7, 10>;
+      let two: Seven = Seven::from_int(2);
+      let curve: Poly<Seven> = Poly::new(vec![-two, Seven::ZERO, Seven::ONE]);
+      let roots: Vec<Seven> = curve.padic_roots();
+      assert_eq!(roots.len(), 2);
+      assert_eq!(roots[0] * roots[0], two);
+      assert_eq!(roots[0] + roots[1], Seven::ZERO);
+      let third: PAdic<5> = PAdic::from_rat(Rat::new(50, 3));
+      assert_eq!(third.valuation(), Some(2));
+      assert_eq!(third * PAdic::from_int(3), PAdic::from_int(50));
+      assert_eq!(PAdic::<3, 4>::from_int(10).to_string(), "1 + 1*3^2 + O(3^4)");
+      assert_eq!(PAdic::<3, 4>::from_rat(Rat::new(1, 3)).latex(), "1 \\cdot 3^{-1} + O(3^{3})");
+   }
+   #[test]
+   fn surds() {
+      type Golden = QuadField<i64, 5>;
+      let golden: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(-1), Rat::whole(-1), Rat::ONE]);
+      let (phi, psi): (Golden, Golden) = golden.quadratic_roots().unwrap();
+      assert_eq!(phi, Golden::new(Rat::new(1, 2), Rat::new(1, 2)));
+      assert_eq!(phi * psi, Golden::whole(-Rat::ONE));
+      assert_eq!(phi.norm(), -Rat::ONE);
+      assert_eq!(phi * phi.inv(), Golden::ONE);
+      assert!(golden.quadratic_roots::<2>().is_none());
+      let line: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(-1), Rat::ONE]);
+      assert!(line.quadratic_roots::<5>().is_none());
+      let unit: Poly<Rat<i64>> = Poly::new(vec![-Rat::ONE, Rat::ZERO, Rat::ONE]);
+      assert_eq!(unit.quadratic_roots::<2>(), Some((QuadField::whole(Rat::ONE), QuadField::whole(-Rat::ONE))));
+      let split: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(6), Rat::whole(-5), Rat::ONE]);
+      assert_eq!(split.quadratic_roots::<5>(), Some((QuadField::whole(Rat::whole(3)), QuadField::whole(Rat::whole(2)))));
+      let wide: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(-(i64::MAX / 8)), Rat::ZERO, Rat::ONE]);
+      assert!(wide.quadratic_roots::<5>().is_none());
+      assert_eq!(phi.latex(), "\\frac{1}{2} + \\frac{1}{2}\\sqrt{5}");
+      assert_eq!(psi.to_string(), "(1/2)-(1/2)√5");
+   }
+   #[test]
+   fn algebraics() {
+      let root = |n: i64| -> Algebraic<i64> {
+         Algebraic::roots(&Poly::new(vec![Rat::whole(-n), Rat::ZERO, Rat::ONE])).pop().unwrap()
+      };
+      let sum: Algebraic<i64> = root(2) + root(3);
+      assert_eq!(sum.degree(), 4);
+      assert!(sum > Algebraic::from_rat(Rat::new(314, 100)));
+      assert!(sum < Algebraic::from_rat(Rat::new(315, 100)));
+      assert_eq!(root(2) * root(3), root(6));
+      assert!(root(2) * root(3) < sum);
+      assert_eq!(root(2) * root(2), Algebraic::from_rat(Rat::whole(2)));
+      assert_eq!(root(2) - root(2), Algebraic::from_rat(Rat::ZERO));
+      let cubic: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(2), Rat::whole(-1), Rat::whole(-2), Rat::ONE]);
+      let roots: Vec<Algebraic<i64>> = Algebraic::roots(&cubic);
+      assert_eq!(roots.len(), 3);
+      assert_eq!(roots[0], Algebraic::from_rat(-Rat::ONE));
+      assert_eq!(roots[2], Algebraic::from_rat(Rat::whole(2)));
+      assert_eq!(roots.iter().map(Algebraic::degree).collect::<Vec<usize>>(), vec![1, 1, 1]);
+      assert_eq!((root(2) * root(2)).as_rat(), Some(Rat::whole(2)));
+      let mixed: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(2), Rat::whole(-2), -Rat::ONE, Rat::ONE]);
+      let roots: Vec<Algebraic<i64>> = Algebraic::roots(&mixed);
+      assert_eq!(roots.iter().map(Algebraic::degree).collect::<Vec<usize>>(), vec![2, 1, 2]);
+      assert_eq!(roots[1].as_rat(), Some(Rat::ONE));
+      assert_eq!(roots[2], root(2));
+      let third: Poly<Rat<i64>> = Poly::new(vec![Rat::whole(2), Rat::whole(-6), -Rat::ONE, Rat::whole(3)]);
+      assert_eq!(Algebraic::roots(&third)[1].as_rat(), Some(Rat::new(1, 3)));
+   }
+   #[test]
+   fn posits() {
+      type Tiny = Posit<8, 0>;
+      assert_eq!(Tiny::ONE.bits, 0x40);
+      assert_eq!((Tiny::ONE + Tiny::ONE).bits, 0x60);
+      assert_eq!(Tiny::from_f64(0.5).bits, 0x20);
+      assert_eq!((-Tiny::ONE).bits, 0xc0);
+      assert_eq!(Tiny::from_f64(1e9), Tiny::MAXPOS);
+      assert!(Tiny::NAR < -Tiny::MAXPOS && -Tiny::ONE < Tiny::ZERO);
+      assert_eq!((Tiny::ONE / Tiny::ZERO).to_string(), "NaR");
+      assert_eq!(p16::from_f64(3.0) * p16::from_f64(7.0), p16::from_f64(21.0));
+      assert!((p32::PI.to_f64() - std::f64::consts::PI).abs() < 1e-7);
+      assert!((p32::ONE.exp(30).to_f64() - std::f64::consts::E).abs() < 1e-6);
+      assert!((p32::HALFPI.xsin(30).to_f64() - 1.0).abs() < 1e-6);
+      let big: p16 = p16::from_f64(1048576.0);
+      let (lhs, rhs): ([p16; 3], [p16; 3]) = ([big, p16::ONE, -big], [p16::ONE; 3]);
+      assert_eq!(big + p16::ONE - big, p16::ZERO);
+      assert_eq!(p16::dot(&lhs, &rhs), p16::ONE);
+   }
+   #[test]
+   fn fractions() {
+      let pi: ContinuedFraction<i64> = ContinuedFraction::from_real(std::f64::consts::PI, 5);
+      assert_eq!(pi.terms, vec![3, 7, 15, 1, 292]);
+      assert_eq!(pi.convergents()[3], Rat::new(355, 113));
+      assert_eq!(pi.to_string(), "[3; 7, 15, 1, 292]");
+      assert_eq!(Rat::approx(std::f64::consts::PI, 100i64), Rat::new(311, 99));
+      assert_eq!(Rat::approx(2f64.powf(1.0 / 12.0), 20i32), Rat::new(18, 17));
+      let (nan, minus): (Rat<i64>, Rat<i64>) = (Rat::approx(f64::NAN, 100), Rat::approx(f64::NEG_INFINITY, 100));
+      assert_eq!((nan.n, nan.d, minus.n, minus.d), (0, 0, -1, 0));
+      assert_eq!(Rat::approx(f64::INFINITY, 100i64), Rat::new(1, 0));
+      let ratio: ContinuedFraction<i32> = ContinuedFraction::from_rat(Rat::new(-17, 5));
+      assert_eq!(ratio.terms, vec![-4, 1, 1, 2]);
+      assert_eq!(ratio.value(), Rat::new(-17, 5));
+      assert_eq!(ratio.semiconvergents(), vec![Rat::new(-10, 3)]);
+      assert_eq!(Rat::new(355, 113).limit(50), Rat::new(22, 7));
+      assert_eq!(ContinuedFraction::new(vec![1, 2]).latex(), "1 + \\cfrac{1}{2}");
+   }
+   #[test]
+   fn overflow() {
+      let big: Rat<i32> = Rat::new(1 << 20, 3);
+      let small: Rat<i32> = Rat::new(3, 1 << 20);
+      assert_eq!(big * small, Rat::ONE);
+      assert_eq!(big.checked_mul(small), Some(Rat::ONE));
+      assert_eq!(big.checked_mul(big), None);
+      assert_eq!(big.checked_add(Rat::new(1, 7)), Some(Rat::new(7340035, 21)));
+      assert_eq!(Rat::<i32>::whole(i32::MAX).checked_add(Rat::ONE), None);
+      assert_eq!(Rat::new(-4, 6), Rat::new(2, -3));
+      assert!(Rat::new(i32::MAX, 2) > Rat::new(i32::MAX - 1, 2));
+      assert!(Rat::new(1, i32::MAX) < Rat::new(1, i32::MAX - 1));
+      assert_eq!(big.saturating_mul(big), Rat::whole(i32::MAX));
+      assert_eq!((-big).saturating_mul(big), Rat::whole(-i32::MAX));
+      assert_eq!(Rat::<i8>::new(1, 10).saturating_mul(Rat::new(1, 13)), Rat::new(1, 127));
+      assert_eq!(Rat::<i8>::new(1, 100).saturating_mul(Rat::new(1, 100)), Rat::ZERO);
+      assert_eq!(Rat::<i8>::new(100, 3).saturating_add(Rat::new(1, 7)), Rat::new(67, 2));
+      assert_eq!(big.promote() * big.promote(), Rat::<i64>::new(1 << 40, 9));
+      let low: Rat<i32> = Rat::whole(i32::MIN);
+      assert_eq!(Rat::<i32>::whole(i32::MIN + 1).checked_sub(Rat::ONE), Some(low));
+      assert_eq!(Rat::<i32>::whole(-(1 << 30)).checked_mul(Rat::whole(2)), Some(low));
+      assert_eq!(low.checked_mul(Rat::whole(-1)), None);
+      assert_eq!(low.checked_neg(), None);
+      assert_eq!(low.checked_div(Rat::whole(-2)), Some(Rat::whole(1 << 30)));
+      assert_eq!(Rat::checked_new(i32::MIN, -1), None);
+      assert_eq!(Rat::checked_new(i32::MIN, i32::MIN), Some(Rat::ONE));
+      assert_eq!(Rat::checked_new(i32::MAX, -i32::MAX), Some(-Rat::ONE));
+      assert_eq!(Rat::<i32>::whole(i32::MAX).checked_sub(Rat::whole(i32::MIN)), None);
+      assert_eq!(Rat::<i64>::new(2, 9).limit(2), Rat::ZERO);
+      assert_eq!(Rat::<i64>::new(7, 9).limit(2), Rat::ONE);
+   }
+   #[test]
+   fn rational_reals() {
+      let close = |q: Rat<i64>, x: f64| (q.n as f64 / q.d as f64 - x).abs() < 1e-5;
+      let one: Rat<i64> = Rat::ONE;
+      assert!(close(one.exp(30), std::f64::consts::E));
+      assert!(close(Rat::whole(2).lnn(60), std::f64::consts::LN_2));
+      assert!(close(one.xsin(30), 1f64.sin()));
+      assert!(close(one.xatan(30), std::f64::consts::FRAC_PI_4));
+      assert_eq!(Rat::<i64>::whole(2).rrt(Rat::order_of(-8)), Rat::new(19601, 13860));
+      assert_eq!(Rat::<i64>::HALFPI.xsin(30), Rat::ONE);
+      assert_eq!(Rat::<i64>::new(3, 0), Rat::INFINITE);
+      assert!(Rat::<i64>::INFINITE > Rat::whole(i64::MAX) && -Rat::<i64>::INFINITE < Rat::ZERO);
+      assert!(Rat::<i64>::UNDEF != Rat::UNDEF);
+      assert_eq!(Rat::ZERO.inv() + Rat::ONE, Rat::<i64>::INFINITE);
+      assert_eq!(Rat::<i32>::new(355, 113).settle(), Rat::new(22, 7));
+      assert_eq!(Rat::<i32>::QTRPI * Rat::whole(4), Rat::PI);
+      assert_eq!(Rat::<i64>::HALFPI + Rat::HALFPI, Rat::PI);
+      assert_eq!(Rat::<i128>::QTRPI * Rat::whole(4), Rat::TAU / Rat::whole(2));
+      assert_eq!(Rat::<isize>::QTRPI * Rat::whole(4), Rat::PI);
+      assert_eq!(<isize as RoundingPolicy>::PI.0 as i64, if isize::BITS == 64 { i64::PI.0 } else { i32::PI.0 as i64 });
+   }
+   #[test]
+   #[should_panic(expected = "rounding of an infinite or undefined Rat")]
+   fn infinite_floor() {
+      Rat::<i64>::INFINITE.floor();
+   }
+   #[test]
+   fn decimals() {
+      assert_eq!("3/4".parse::<Rat<i64>>(), Ok(Rat::new(3, 4)));
+      assert_eq!("-2".parse::<Rat<i64>>(), Ok(Rat::whole(-2)));
+      assert_eq!("0.125".parse::<Rat<i64>>(), Ok(Rat::new(1, 8)));
+      assert_eq!("0.1(6)".parse::<Rat<i64>>(), Ok(Rat::new(1, 6)));
+      assert_eq!("-1.(142857)".parse::<Rat<i32>>(), Ok(Rat::new(-8, 7)));
+      assert_eq!("1.5/0.(3)".parse::<Rat<i32>>(), Ok(Rat::new(9, 2)));
+      assert!("1.2.3".parse::<Rat<i32>>().is_err() && "0.()".parse::<Rat<i32>>().is_err());
+      assert!("".parse::<Rat<i32>>().is_err() && "x".parse::<Rat<i32>>().is_err());
+      assert!("0.00000000000000000001".parse::<Rat<i64>>().is_err());
+      assert!("0.00000000000000000001".parse::<Rat<i32>>().is_err());
+      assert!("0.(0000000001)".parse::<Rat<i32>>().is_err());
+      assert_eq!("0.000000001".parse::<Rat<i32>>(), Ok(Rat::new(1, 1000000000)));
+      assert_eq!("2/0".parse::<Rat<i32>>(), Ok(Rat::INFINITE));
+      let wide: String = Rat::<i64>::new(1, 100003).decimal();
+      assert_eq!(wide.len() as i64, multiplicative_order(10, 100003).unwrap() + 4);
+      assert!(wide.starts_with("0.(0000099997"));
+      assert_eq!(Rat::<i64>::new(1, 6).decimal(), "0.1(6)");
+      assert_eq!(Rat::<i64>::new(-22, 7).decimal(), "-3.(142857)");
+      assert_eq!(Rat::<i64>::new(-1, 8).decimal(), "-0.125");
+      assert_eq!(Rat::<i64>::new(12, 4).decimal(), "3");
+      assert_eq!(Rat::<i64>::new(1, 0).decimal(), "∞");
+      assert_eq!(Rat::<i64>::new(1, 1 << 62).decimal(), "0.00000000000000000021684043449710088680149056017398834228515625");
+      assert_eq!(Rat::<i64>::new(7, 3 << 61).decimal(), "0.0000000000000000010119220276531374717402892808119455973307291(6)");
+      assert_eq!(Rat::<i128>::new(-1, 5i128.pow(54)).decimal(), "-0.000000000000000000000000000000000000018014398509481984");
+      let odd: Rat<i64> = Rat::new(3227, 555);
+      assert_eq!(odd.decimal().parse::<Rat<i64>>(), Ok(odd));
+   }
+   #[test]
+   fn representations() {
+      let q: Rat<i64> = Rat::new(-7, 2);
+      assert_eq!((q.floor(), q.ceil(), q.round(), q.trunc()), (-4, -3, -4, -3));
+      assert_eq!(Rat::<i64>::new(7, 3).mixed(), (2, Rat::new(1, 3)));
+      assert_eq!(Rat::<i64>::new(7, 3).mixed_string(), "2 1/3");
+      assert_eq!(Rat::<i64>::new(-7, 3).mixed_string(), "-2 1/3");
+      assert_eq!(Rat::<i64>::new(-1, 3).latex_mixed(), "-\\frac{1}{3}");
+      assert_eq!(Rat::<i64>::new(7, 3).latex_mixed(), "2\\frac{1}{3}");
+      assert_eq!(Rat::<i64>::new(4, 13).greedy_egyptian(), Some((0, vec![4, 18, 468])));
+      assert_eq!(Rat::<i64>::new(4, 17).greedy_egyptian(), Some((0, vec![5, 29, 1233, 3039345])));
+      assert_eq!(Rat::<i64>::new(4, 17).shortest_egyptian(), (0, vec![6, 17, 102]));
+      assert_eq!(Rat::<i64>::new(3, 2).greedy_egyptian(), Some((1, vec![2])));
+      assert_eq!(Rat::<i64>::new(-3, 4).greedy_egyptian(), Some((-1, vec![4])));
+      assert_eq!(Rat::<i64>::new(5, 121).greedy_egyptian(), None);
+      assert_eq!(Rat::<i128>::new(5, 121).greedy_egyptian(), Some((0, vec![25, 757, 763309, 873960180913, 1527612795642093418846225])));
+      assert_eq!(Rat::<i64>::whole(2).shortest_egyptian(), (2, vec![]));
+      assert_eq!(Rat::<i64>::new(11, 6).latex_egyptian(true), Some("1 + \\frac{1}{2} + \\frac{1}{3}".to_string()));
+      assert_eq!(Rat::<i64>::ZERO.latex_egyptian(false), Some("0".to_string()));
+      assert_eq!(Rat::<i64>::new(5, 6).latex_egyptian(true), Some("\\frac{1}{2} + \\frac{1}{3}".to_string()));
+      assert_eq!(q.latex_floor(), "\\left\\lfloor \\frac{-7}{2} \\right\\rfloor = -4");
+   }
+   #[test]
+   fn trees() {
+      let farey: Vec<Rat<i32>> = Farey::new(5).collect();
+      assert_eq!(farey.len(), 11);
+      assert_eq!(farey[1..4], [Rat::new(1, 5), Rat::new(1, 4), Rat::new(1, 3)]);
+      assert_eq!(farey[10], Rat::ONE);
+      let node: SternBrocot<i64> = SternBrocot::find(Rat::new(3, 5));
+      assert_eq!(node.path(), vec![Branch::Left, Branch::Right, Branch::Left]);
+      assert_eq!(SternBrocot::<i64>::follow(&node.path()).value(), Rat::new(3, 5));
+      assert_eq!(node.parent().unwrap().value(), Rat::new(2, 3));
+      let (left, right): (SternBrocot<i64>, SternBrocot<i64>) = node.children();
+      assert_eq!((left.value(), right.value()), (Rat::new(4, 7), Rat::new(5, 8)));
+      assert_eq!(Rat::<i64>::new(1, 2).mediant(Rat::new(2, 3)), Rat::new(3, 5));
+      assert!(SternBrocot::<i64>::root().parent().is_none());
+      let wilf: Vec<Rat<i64>> = CalkinWilf::new().take(7).collect();
+      assert_eq!(wilf, vec![Rat::ONE, Rat::new(1, 2), Rat::whole(2), Rat::new(1, 3), Rat::new(3, 2), Rat::new(2, 3), Rat::whole(3)]);
+   }
+   #[test]
+   fn gaussians() {
+      let (a, b): (g64, g64) = (Comp::new(4, 7), Comp::new(1, -3));
+      let (q, r): (g64, g64) = a.divrem(b);
+      assert_eq!(q * b + r, a);
+      assert!(r.norm() < b.norm());
+      assert_eq!(Comp::<i64>::new(11, 3).gcd(Comp::new(1, 8)), Comp::new(2, 1));
+      let (g, s, t): (g64, g64, g64) = Comp::<i64>::new(32, 9).ext_gcd(Comp::new(4, 11));
+      assert_eq!(s * Comp::new(32, 9) + t * Comp::new(4, 11), g);
+      assert!(g.is_unit());
+      assert!(Comp::<i32>::new(3, 0).is_gaussian_prime() && !Comp::<i32>::new(5, 0).is_gaussian_prime());
+      assert!(Comp::<i32>::new(2, 1).is_gaussian_prime() && Comp::<i32>::new(0, -7).is_gaussian_prime());
+      assert_eq!(Comp::<i32>::new(0, -1).normalized(), Comp::ONE);
+      let (unit, primes): (g32, Vec<g32>) = Comp::<i32>::nre(30).factor();
+      assert_eq!(primes.iter().fold(unit, |product, p| product * *p), Comp::nre(30));
+      assert_eq!(primes, vec![Comp::new(1, 1), Comp::new(1, 1), Comp::nre(3), Comp::new(1, 2), Comp::new(2, 1)]);
+      assert!(Comp::<i64>::new(1000000000, 999999999).is_gaussian_prime() == is_prime(1999999998000000001i64));
+      let (unit, primes): (g64, Vec<g64>) = Comp::<i64>::nre(1000033 * 13).factor();
+      assert_eq!(primes.iter().fold(unit, |product, p| product * *p), Comp::nre(1000033 * 13));
+      assert!(primes.iter().all(|p| p.is_gaussian_prime()));
+   }
+   #[test]
+   fn eisensteins() {
+      let omega: Eisenstein<i64> = Eisenstein::omega();
+      assert_eq!(omega * omega * omega, Eisenstein::ONE);
+      assert_eq!(omega * omega + omega + Eisenstein::ONE, Eisenstein::ZERO);
+      let (x, y): (Eisenstein<i64>, Eisenstein<i64>) = (Eisenstein::new(17, 5), Eisenstein::new(3, -4));
+      let (q, r): (Eisenstein<i64>, Eisenstein<i64>) = x.divrem(y);
+      assert_eq!(q * y + r, x);
+      assert!(r.norm() < y.norm());
+      assert_eq!((x * y).norm(), x.norm() * y.norm());
+      let three: Eisenstein<i64> = Eisenstein::new(3, 0);
+      assert!(!three.is_eisenstein_prime());
+      assert!(Eisenstein::<i64>::new(1, -1).is_eisenstein_prime());
+      assert!(Eisenstein::<i64>::new(2, 0).is_eisenstein_prime() && Eisenstein::<i64>::new(0, -5).is_eisenstein_prime());
+      assert!(!Eisenstein::<i64>::new(7, 0).is_eisenstein_prime() && Eisenstein::<i64>::new(3, 1).is_eisenstein_prime());
+      assert_eq!(three.gcd(Eisenstein::new(7, 0) * Eisenstein::new(1, -1)), Eisenstein::new(1, -1).normalized());
+      assert_eq!(Eisenstein::<i64>::units().iter().filter(|u| u.is_unit()).count(), 6);
+      assert_eq!(Eisenstein::new(2, -1).latex(), "2-1\\omega");
+   }
+   #[test]
+   fn dimensions() {
+      let distance: Quantity<f64, Length> = Quantity::new(100.0);
+      let time: Quantity<f64, Time> = Quantity::new(9.58);
+      let speed: Quantity<f64, Velocity> = distance / time;
+      assert_eq!(Quantity::<f64, Velocity>::exponents(), [1, 0, -1, 0, 0, 0, 0]);
+      assert!(speed > Quantity::<f64, Quotient<Length, Time>>::new(10.0));
+      let field: Quantity<f64, Area> = Quantity::<f64, Length>::new(3.0) * Quantity::<f64, Length>::new(12.0);
+      assert!((field.rrt(1e-12) - Quantity::<f64, Length>::new(6.0)).value.abs() < 1e-6);
+      let work = Quantity::<f64, Force>::new(2.0) * Quantity::<f64, Length>::new(5.0);
+      assert_eq!(work.cast::<Energy>().value, 10.0);
+      assert_eq!(work.to_string(), "10 m^2 kg s^-2");
+      assert_eq!(Quantity::<i32, Velocity>::new(3).latex(), "3\\,\\mathrm{m}\\,\\mathrm{s}^{-1}");
+      let mut total: Quantity<Rat<i64>, Mass> = Quantity::new(Rat::new(1, 2));
+      total += Quantity::<Rat<i64>, Mass>::new(Rat::new(1, 3));
+      assert_eq!(total, Quantity::<Rat<i64>, Mass>::new(Rat::new(5, 6)));
+   }
+   #[test]
+   fn primes() {
+      assert!(is_prime(2i8) && is_prime(127i8) && !is_prime(1i32) && !is_prime(-7i64));
+      assert!(is_prime(18446744073709551557i128) && !is_prime(3215031751i64));
+      assert!(is_prime(170141183460469231731687303715884105727i128));
+      assert!(!is_prime(170141183460469231731687303715884105727i128 - 2));
+      assert_eq!(primes_between(90i32, 110), vec![97, 101, 103, 107, 109]);
+      assert_eq!(Primes::<i64>::new().take(6).collect::<Vec<i64>>(), vec![2, 3, 5, 7, 11, 13]);
+      assert_eq!(Primes::<i8>::new().count(), 31);
+      assert_eq!(Primes::starting_at(1000000i64).next(), Some(1000003));
+      assert_eq!(next_prime(13i32), Some(17));
+      assert_eq!(next_prime(127i8), None);
+      assert_eq!(prev_prime(13i32), Some(11));
+      assert_eq!(prev_prime(2i32), None);
+      assert_eq!(prime_count(100i32), 25);
+      assert_eq!(prime_count(10000000i64), 664579);
+      assert_eq!(primes_between(0i64, 1 << 20).len(), 82025);
+   }
+   #[test]
+   fn factoring() {
+      assert_eq!(factor(360i32), vec![(2, 3), (3, 2), (5, 1)]);
+      assert_eq!(factor(-97i64), vec![(97, 1)]);
+      assert!(factor(1i64).is_empty());
+      assert_eq!(factor(600851475143i64), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+      assert_eq!(factor(10403i64 * 1000003 * 1000003), vec![(101, 1), (103, 1), (1000003, 2)]);
+      assert_eq!(factor(4611686014132420609i64), vec![(2147483647, 2)]);
+      assert_eq!(factor(1000000007i128 * 998244353 * 1000000009), vec![(998244353, 1), (1000000007, 1), (1000000009, 1)]);
+      assert_eq!(factor(u64::MAX as i128), vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]);
+      assert_eq!(divisors(28i32), vec![1, 2, 4, 7, 14, 28]);
+      assert_eq!(divisor_sum(28i32), 56);
+      assert_eq!(divisor_count(360i32), 24);
+      assert_eq!(totient(36i32), 12);
+      assert_eq!((mobius(30i32), mobius(12i32), mobius(1i32)), (-1, 0, 1));
+      assert_eq!(factor_u64(u64::MAX), vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]);
+      assert!(is_prime_u64(18446744073709551557) && !is_prime_u64(u64::MAX));
+      assert_eq!(factor_u64(18446744073709551557), vec![(18446744073709551557, 1)]);
+      assert_eq!(factor_u128(1 << 127), vec![(2, 127)]);
+      assert_eq!(factor_u128(4294967291 * 4294967279 * 3), vec![(3, 1), (4294967279, 1), (4294967291, 1)]);
+      assert!(is_prime_u128(170141183460469231731687303715884105727));
+      assert_eq!(factor_u128(u128::MAX), vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (274177, 1), (6700417, 1), (67280421310721, 1)]);
+      // past 2^127, where the p - 1 method and the curve inversions must not go through i128
+      assert_eq!(nt::pollard_pm1(10187795818971963743 * 16700490124039209647), Some(10187795818971963743));
+      assert_eq!(nt::inverse_or_factor(2, u128::MAX), Ok(1 << 127));
+      assert_eq!(nt::inverse_or_factor(u128::MAX - 5, u128::MAX), Err(5));
+   }
+   #[test]
+   fn congruences() {
+      assert_eq!(ext_gcd(240i64, 46), (2, -9, 47));
+      assert_eq!(ext_gcd(-12i32, 18).0, 6);
+      assert_eq!(gcd(-12i32, -18), 6);
+      assert_eq!(lcm(4i32, -6), Some(12));
+      assert_eq!(lcm(i64::MAX, 2), None);
+      assert_eq!(lcm(i64::MAX, i64::MAX), Some(i64::MAX));
+      assert_eq!(mod_inverse(3i64, 11), Some(4));
+      assert_eq!(mod_inverse(-3i64, 11), Some(7));
+      assert_eq!(mod_inverse(6i64, 9), None);
+      assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+      assert_eq!(crt(&[(2i64, 6), (8, 10)]), Some((8, 30)));
+      assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+      assert_eq!(crt(&[(2i64, 3), (1_099_511_627_000, 1_099_511_627_791)]), Some((1099511627000, 3298534883373)));
+      assert_eq!(crt(&[(1i32, 65521), (2, 65519)]), None);
+      assert_eq!(crt(&[(-1i64, 7)]), Some((6, 7)));
+      assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+   }
+   #[test]
+   fn logarithms() {
+      assert_eq!(mod_pow(4i64, 13, 497), 445);
+      assert_eq!(mod_pow(3i64, -1, 11), 4);
+      assert_eq!(mod_pow(2i128, 100, 1000000007), 976371285);
+      assert_eq!(mod_sqrt(10i64, 13), Some(6));
+      assert_eq!(mod_sqrt(5i64, 13), None);
+      assert_eq!(mod_sqrt(2i64, 113), Some(51));
+      assert_eq!(mod_sqrt(1000000006i64, 1000000007), None);
+      assert_eq!(multiplicative_order(2i64, 7), Some(3));
+      assert_eq!(multiplicative_order(2i64, 8), None);
+      assert_eq!(primitive_root(7i64), Some(3));
+      assert_eq!(primitive_root(1000000007i64), Some(5));
+      assert_eq!(primitive_root(8i64), None);
+      assert_eq!(primitive_root(50i64), Some(3));
+      assert_eq!(discrete_log(3i64, 13, 17), Some(4));
+      assert_eq!(discrete_log(5i64, mod_pow(5, 123456789, 1000000007), 1000000007), Some(123456789));
+      assert_eq!(discrete_log(2i64, 3, 7), None);
+      assert_eq!(discrete_log_bsgs(3i64, 13, 17), Some(4));
+      assert_eq!(discrete_log(2i64, 4, 8), Some(2));
+      assert_eq!(discrete_log(2i64, 0, 8), Some(3));
+      assert_eq!(discrete_log(2i64, 3, 8), None);
+      assert_eq!(discrete_log(6i64, 12, 1 << 40), None);
+      let (base, modulus): (i64, i64) = (12, (1 << 10) * 3 * 5 * 7 * 11 * 13);
+      assert_eq!(discrete_log(base, mod_pow(base, 2000, modulus), modulus).map(|x| mod_pow(base, x, modulus)), Some(mod_pow(base, 2000, modulus)));
+      assert_eq!(discrete_log_bsgs(2i64, 4, 8), Some(2));
+      assert_eq!(discrete_log_bsgs(10i64, mod_pow(10, 300, 3 * 2 * 1000003), 3 * 2 * 1000003), discrete_log(10, mod_pow(10, 300, 6000018), 6000018));
+      assert_eq!(mod_sqrt(4i64, 8), Some(2));
+      assert_eq!(mod_sqrts(4i64, 8), vec![2, 6]);
+      assert_eq!(mod_sqrts(1i64, 16), vec![1, 7, 9, 15]);
+      assert_eq!(mod_sqrts(0i64, 27), vec![0, 9, 18]);
+      assert_eq!(mod_sqrts(9i64, 27), vec![3, 6, 12, 15, 21, 24]);
+      assert_eq!(mod_sqrts(-1i64, 65), vec![8, 18, 47, 57]);
+      assert_eq!(mod_sqrts(3i64, 65), Vec::<i64>::new());
+      for m in 1i64..100 {
+         for a in 0..m {
+            let brute: Vec<i64> = (0..m).filter(|x| x * x % m == a).collect();
+            assert_eq!(mod_sqrts(a, m), brute);
+         }
+      }
+      assert_eq!(mod_sqrts(2i64, 7 * 7 * 7 * 7 * 7).len(), 2);
+   }
+   #[test]
+   fn residuosity() {
+      assert_eq!(jacobi(1001i64, 9907), -1);
+      assert_eq!(jacobi(19i64, 45), 1);
+      assert_eq!(jacobi(6i64, 15), 0);
+      assert_eq!(legendre(10i64, 13), 1);
+      assert_eq!(legendre(5i64, 13), -1);
+      assert_eq!((kronecker(5i64, 12), kronecker(3i64, -8), kronecker(2i64, 0), kronecker(1i64, 0)), (-1, -1, 0, 1));
+      assert_eq!(quadratic_residues(11i32), vec![0, 1, 3, 4, 5, 9]);
+      assert_eq!(quadratic_residues(8i32), vec![0, 1, 4]);
+      assert_eq!(cornacchia(1i64, 13), Some((3, 2)));
+      assert_eq!(cornacchia(2i64, 11), Some((3, 1)));
+      assert_eq!(cornacchia(3i64, 7), Some((2, 1)));
+      assert_eq!(cornacchia(1i64, 21), None);
+      assert_eq!(cornacchia(1i64, 65).map(|(x, y)| x * x + y * y), Some(65));
+      assert_eq!(cornacchia(7i64, 28), None);
+      assert_eq!(cornacchia(1i64, 18), None);
+      for m in 2i64..120 {
+         for d in 1..m {
+            if let Some((x, y)) = cornacchia(d, m) {
+               assert_eq!((x * x + d * y * y, gcd(x, y)), (m, 1));
+            }
+         }
+      }
+      assert_eq!(cornacchia(1i64, 5 * 13 * 17 * 29 * 37 * 41).map(|(x, y)| x * x + y * y), Some(5 * 13 * 17 * 29 * 37 * 41));
+      assert_eq!(sum_of_two_squares(325i64), Some((1, 18)));
+      assert_eq!(sum_of_two_squares(98i64), Some((7, 7)));
+      assert_eq!(sum_of_two_squares(21i64), None);
+      let (a, b): (i64, i64) = sum_of_two_squares(1000000009i64 * 1000000021).unwrap();
+      assert_eq!(a as i128 * a as i128 + b as i128 * b as i128, 1000000009i128 * 1000000021);
+   }
+   #[test]
+   fn diophantine() {
+      assert_eq!(ContinuedFraction::of_sqrt(14i64).terms, vec![3, 1, 2, 1, 6]);
+      assert_eq!(ContinuedFraction::of_sqrt(16i64).terms, vec![4]);
+      assert_eq!(ContinuedFraction::of_sqrt(i8::MAX).terms, vec![11, 3, 1, 2, 2, 7, 11, 7, 2, 2, 1, 3, 22]);
+      let wide: ContinuedFraction<i32> = ContinuedFraction::of_sqrt(i32::MAX);
+      assert_eq!((wide.terms.len(), wide.terms[wide.terms.len()-1]), (13005, 92680));
+      assert_eq!(pell(2i64), Some((3, 2)));
+      assert_eq!(pell(13i64), Some((649, 180)));
+      assert_eq!(pell(61i64), Some((1766319049, 226153980)));
+      assert_eq!(pell(9i64), None);
+      let negative: Vec<(i64, i64)> = PellSolutions::new(2i64, -1).unwrap().take(3).collect();
+      assert_eq!(negative, vec![(1, 1), (7, 5), (41, 29)]);
+      let seven: Vec<(i64, i64)> = PellSolutions::new(2i64, 7).unwrap().take(4).collect();
+      assert_eq!(seven, vec![(3, 1), (5, 3), (13, 9), (27, 19)]);
+      assert_eq!(PellSolutions::new(3i32, 1).unwrap().take(3).collect::<Vec<(i32, i32)>>(), vec![(1, 0), (2, 1), (7, 4)]);
+      assert_eq!(PellSolutions::<i64>::new(3, 2).unwrap().next(), None);
+      let line: LinearSolution<i64> = linear_diophantine(12, 18, 30).unwrap();
+      assert_eq!((line.x, line.y, line.dx, line.dy), (1, 1, 3, -2));
+      assert_eq!(line.at(4), (13, -7));
+      assert_eq!(linear_diophantine(4i64, 6, 5), None);
+      assert_eq!(linear_diophantine(0i64, 0, 0), None);
+      assert_eq!(pell_fundamental(661i64, 1), Some(vec![(1, 0)]));
+      assert_eq!(pell_fundamental(2i64, 7), Some(vec![(3, 1)]));
+      assert_eq!(pell_fundamental(1000099i64, 2), None);
+      assert!(PellSolutions::<i64>::new(1000099, 1).is_none());
+      assert_eq!((pell_fundamental(9i64, 2), pell_fundamental(-2i64, 1)), (None, None));
+      assert!(PellSolutions::<i64>::new(16, 1).is_none());
+   }
+   #[test]
+   fn roots() {
+      assert_eq!(isqrt(99i64), 9);
+      assert_eq!(isqrt(i128::MAX), 13043817825332782212);
+      assert_eq!(iroot(i128::MAX, 3), 5541191377756);
+      assert_eq!(iroot(i128::MIN, 3), -5541191377756);
+      assert_eq!(iroot(i128::MAX, 5), 44275338);
+      assert_eq!(iroot(i128::MAX, 126), 2);
+      assert_eq!(iroot(i128::MAX, 127), 1);
+      assert_eq!(isqrt(i128::MAX - (1 << 80)), 13043817825332735871);
+      // the one root routine that quad and cf now share, at the edge of each width
+      assert_eq!((isqrt(i8::MAX), isqrt(i16::MAX), isqrt(i32::MAX)), (11, 181, 46340));
+      assert_eq!((exact_root(i8::MAX, 2), exact_root(121i8, 2), exact_root(i64::MAX, 3)), (None, Some(11), None));
+      assert_eq!(icbrt(-28i32), -3);
+      assert_eq!(iroot(1i64 << 62, 31), 4);
+      assert_eq!(iroot(i64::MAX, 3), 2097151);
+      assert_eq!(exact_root(3125i64, 5), Some(5));
+      assert_eq!(exact_root(3126i64, 5), None);
+      assert_eq!(exact_root(-16i64, 2), None);
+      assert_eq!(is_perfect_power(4096i64), Some((2, 12)));
+      assert_eq!(is_perfect_power(-216i64), Some((-6, 3)));
+      assert_eq!(is_perfect_power(-64i64), Some((-4, 3)));
+      assert_eq!(is_perfect_power(72i64), None);
+      assert_eq!(is_perfect_power(i8::MIN), Some((-2, 7)));
+      assert_eq!(Rat::new(-8i64, 27).exact_root(3), Some(Rat::new(-2, 3)));
+      assert_eq!(Rat::new(2i64, 9).exact_root(2), None);
+      assert_eq!(Rat::new(49i64, 4).rrt(Rat::new(1, 1000)), Rat::new(7, 2));
+      assert!((Rat::new(2i64, 1).rrt(Rat::new(1, 1000000)) - Rat::new(1414214, 1000000)).mag2() < Rat::new(1, 100000));
+      assert_eq!(Comp::new(5i64, 12).exact_sqrt(), Some(Comp::new(3, 2)));
+      assert_eq!(Comp::new(-9i64, 0).exact_sqrt(), Some(Comp::new(0, 3)));
+      assert_eq!(Comp::new(0i64, -2).exact_sqrt(), Some(Comp::new(1, -1)));
+      assert_eq!(Comp::new(2i64, 0).exact_sqrt(), None);
+   }
+   #[test]
+   fn counting() {
+      assert_eq!(factorial(20i64), Some(2432902008176640000));
+      assert_eq!(factorial(21i64), None);
+      assert_eq!(falling_factorial(10i32, 3), Some(720));
+      assert_eq!(falling_factorial(3i32, 1000), Some(0));
+      assert_eq!(rising_factorial(-2i32, 2), Some(2));
+      assert_eq!(binomial(10i32, 3), Some(120));
+      assert_eq!(binomial(-4i32, 3), Some(-20));
+      assert_eq!(binomial(5i32, 7), Some(0));
+      assert_eq!(binomial(66i64, 33), Some(7219428434016265740));
+      assert_eq!(binomial(34i32, 17), None);
+      assert_eq!(multinomial(&[2i64, 3, 4]), Some(1260));
+      let catalans: Vec<i64> = (0..8).map(|n| catalan(n).unwrap()).collect();
+      assert_eq!(catalans, vec![1, 1, 2, 5, 14, 42, 132, 429]);
+      assert_eq!(catalan(35i64), Some(3116285494907301262));
+      assert_eq!(stirling_first(5i32, 2), Some(50));
+      assert_eq!(stirling_second(5i32, 2), Some(15));
+      assert_eq!(stirling_second(25i64, 24), Some(300));
+      assert_eq!(stirling_first(20i64, 19), Some(190));
+      assert_eq!(stirling_second(3i32, 0), Some(0));
+      assert_eq!(stirling_first(0i32, 0), Some(1));
+      let bells: Vec<i64> = (0..7).map(|n| bell(n).unwrap()).collect();
+      assert_eq!(bells, vec![1, 1, 2, 5, 15, 52, 203]);
+      assert_eq!(partitions(100i64), Some(190569292));
+      assert_eq!(partitions(0i64), Some(1));
+      assert_eq!(partitions(405i64), Some(9147679068859117602));
+      assert_eq!(partitions(406i64), None);
+      assert_eq!(partitions(1437i128), Some(168434321304033467550147269349447360294));
+      assert_eq!(partitions(1438i128), None);
+      assert_eq!(Rat::new(1i64, 2).choose(3), Some(Rat::new(1, 16)));
+      assert_eq!(Rat::new(-1i64, 2).choose(2), Some(Rat::new(3, 8)));
+      assert_eq!(Rat::new(1i64, 3).falling(2), Some(Rat::new(-2, 9)));
+      assert_eq!(Rat::new(1i64, 3).rising(2), Some(Rat::new(4, 9)));
+   }
+   #[test]
+   fn generation() {
+      let lexical: Vec<Vec<char>> = Permutations::new(&['a', 'b', 'c']).collect();
+      assert_eq!(lexical.len(), 6);
+      assert_eq!(lexical[1], vec!['a', 'c', 'b']);
+      assert_eq!(lexical[5], vec!['c', 'b', 'a']);
+      for (rank, order) in Permutations::new(&[0, 1, 2, 3]).enumerate() {
+         assert_eq!(permutation_rank(&order), rank);
+         assert_eq!(permutation_unrank(4, rank), order);
+      }
+      let heap: Vec<Vec<i32>> = HeapPermutations::new(&[1, 2, 3]).collect();
+      assert_eq!(heap, vec![vec![1, 2, 3], vec![2, 1, 3], vec![3, 1, 2], vec![1, 3, 2], vec![2, 3, 1], vec![3, 2, 1]]);
+      assert_eq!(HeapPermutations::new(&[0; 5]).count(), 120);
+      assert_eq!(Permutations::<i32>::new(&[]).count(), 1);
+      let pairs: Vec<Vec<i32>> = Combinations::new(&[1, 2, 3, 4], 2).collect();
+      assert_eq!(pairs, vec![vec![1, 2], vec![1, 3], vec![1, 4], vec![2, 3], vec![2, 4], vec![3, 4]]);
+      for (rank, chosen) in Combinations::new(&[0, 1, 2, 3, 4, 5], 3).enumerate() {
+         assert_eq!(combination_rank(6, &chosen), rank);
+         assert_eq!(combination_unrank(6, 3, rank), chosen);
+      }
+      assert_eq!(Combinations::new(&[1, 2], 3).count(), 0);
+      let subsets: Vec<Vec<char>> = Subsets::new(&['x', 'y', 'z']).collect();
+      assert_eq!(subsets.len(), 8);
+      assert_eq!(subsets[5], vec!['x', 'z']);
+      assert_eq!(subset_rank(&[0, 2]), 5);
+      assert_eq!(subset_unrank(6), vec![1, 2]);
+      let fives: Vec<Vec<usize>> = Partitions::new(5).collect();
+      assert_eq!(fives, vec![vec![5], vec![4, 1], vec![3, 2], vec![3, 1, 1], vec![2, 2, 1], vec![2, 1, 1, 1], vec![1, 1, 1, 1, 1]]);
+      assert_eq!(Partitions::new(20).count() as i64, partitions(20i64).unwrap());
+      for (rank, parts) in Partitions::new(12).enumerate() {
+         assert_eq!(partition_rank(&parts), rank);
+         assert_eq!(partition_unrank(12, rank), parts);
+      }
+      let fours: Vec<Vec<usize>> = Compositions::new(4).collect();
+      assert_eq!(fours.len(), 8);
+      assert_eq!(fours[0], vec![4]);
+      assert_eq!(fours[7], vec![1, 1, 1, 1]);
+      for (rank, parts) in fours.iter().enumerate() {
+         assert_eq!(parts.iter().sum::<usize>(), 4);
+         assert_eq!(composition_rank(parts), rank);
+      }
+      assert_eq!(Compositions::new(0).collect::<Vec<Vec<usize>>>(), vec![Vec::<usize>::new()]);
+   }
+   #[test]
+   fn special_numbers() {
+      let bernoullis: Vec<Rat<i64>> = (0..9).map(|n| bernoulli(n).unwrap()).collect();
+      assert_eq!(bernoullis, vec![
+         Rat::ONE, Rat::new(-1, 2), Rat::new(1, 6), Rat::ZERO, Rat::new(-1, 30),
+         Rat::ZERO, Rat::new(1, 42), Rat::ZERO, Rat::new(-1, 30)]);
+      assert_eq!(bernoulli(12i64), Some(Rat::new(-691, 2730)));
+      assert_eq!(bernoulli(20i64), Some(Rat::new(-174611, 330)));
+      let eulers: Vec<i64> = (0..11).map(|n| euler(n).unwrap()).collect();
+      assert_eq!(eulers, vec![1, 0, -1, 0, 5, 0, -61, 0, 1385, 0, -50521]);
+      assert_eq!(harmonic(4i64), Some(Rat::new(25, 12)));
+      assert_eq!(harmonic(0i64), Some(Rat::ZERO));
+      assert_eq!(harmonic_power(3i64, 2), Some(Rat::new(49, 36)));
+      assert_eq!(zeta_even(1i64), Some(Rat::new(1, 6)));
+      assert_eq!(zeta_even(2i64), Some(Rat::new(1, 90)));
+      assert_eq!(zeta_even(3i64), Some(Rat::new(1, 945)));
+      assert_eq!(harmonic(100i32), None);
+   }
+   #[test]
+   #[should_panic(expected = "the modulus must be positive")]
+   fn zero_modulus() {
+      crt(&[(1i64, 0)]);
+   }
+   #[test]
+   #[should_panic(expected = "D must be squarefree and not a perfect square")]
+   fn square_surd() {
+      let golden: Poly<Rat<i64>> = Poly::new(vec![-Rat::ONE, -Rat::ONE, Rat::ONE]);
+      golden.quadratic_roots::<12>();
+   }
+}
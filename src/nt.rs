@@ -0,0 +1,954 @@
+use crate::rules::*;
+use std::marker::PhantomData;
+use std::collections::HashMap;
+use crate::cc::Comp;
+use crate::cf::ContinuedFraction;
+
+// enough witnesses for a deterministic Miller-Rabin test below 2^64
+const WITNESSES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+const EXTRA_ROUNDS: usize = 20;
+const SEGMENT: u64 = 1 << 16;
+// past this the base primes get too large to keep, so iterators step with is_prime
+const SIEVE_LIMIT: u64 = 1 << 40;
+
+pub fn is_prime<N: Integer>(n: N) -> bool {
+    let n: i128 = n.to_i128();
+    if n < 2 { return false };
+    prime_u128(n as u128)
+}
+pub fn next_prime<N: Integer>(n: N) -> Option<N> {
+    let mut candidate: i128 = n.to_i128().max(1) + 1;
+    while !prime_u128(candidate as u128) { candidate += 1; }
+    N::from_i128(candidate)
+}
+pub fn prev_prime<N: Integer>(n: N) -> Option<N> {
+    let mut candidate: i128 = n.to_i128() - 1;
+    while candidate >= 2 {
+        if prime_u128(candidate as u128) { return N::from_i128(candidate) };
+        candidate -= 1;
+    }
+    None
+}
+// every prime in [lo, hi)
+pub fn primes_between<N: Integer>(lo: N, hi: N) -> Vec<N> {
+    let (lo, hi): (i128, i128) = (lo.to_i128().max(2), hi.to_i128());
+    if lo >= hi { return Vec::new() };
+    let (lo, hi): (u64, u64) = match (u64::try_from(lo), u64::try_from(hi)) {
+        (Ok(lo), Ok(hi)) => (lo, hi),
+        _ => panic!("sieve bounds must fit in 64 bits"),
+    };
+    let base: Vec<u64> = small_primes((hi - 1).isqrt());
+    let mut result: Vec<N> = Vec::new();
+    let mut start: u64 = lo;
+    while start < hi {
+        let end: u64 = hi.min(start.saturating_add(SEGMENT));
+        result.extend(sieve_segment(start, end, &base).into_iter().filter_map(|p| N::from_i128(p as i128)));
+        start = end;
+    }
+    result
+}
+// the number of primes up to n, by Lucy Hedgehog's method in O(n^(3/4)) time
+pub fn prime_count<N: Integer>(n: N) -> N {
+    let n: i128 = n.to_i128();
+    if n < 2 { return N::ZERO };
+    let n: u64 = match u64::try_from(n) {
+        Ok(n) => n,
+        Err(_) => panic!("prime counting needs a bound that fits in 64 bits"),
+    };
+    let root: u64 = n.isqrt();
+    // small[v] counts up to v, large[i] counts up to n / i
+    let mut small: Vec<u64> = (0..=root).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = (0..=root).map(|i| n.checked_div(i).map_or(0, |v| v - 1)).collect();
+    for p in 2..=root {
+        if small[p as usize] == small[p as usize - 1] { continue };
+        let (below, square): (u64, u64) = (small[p as usize - 1], p * p);
+        for i in 1..=root {
+            if n / i < square { break };
+            let scaled: u64 = i * p;
+            let removed: u64 = if scaled <= root { large[scaled as usize] } else { small[(n / scaled) as usize] };
+            large[i as usize] -= removed - below;
+        }
+        for v in (square..=root).rev() {
+            small[v as usize] -= small[(v / p) as usize] - below;
+        }
+    }
+    match N::from_i128(large[1] as i128) {
+        Some(count) => count,
+        None => panic!("prime count does not fit in the integer type"),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Primes<N: Integer> {
+    start: u128,
+    buffer: Vec<u64>,
+    base: Vec<u64>,
+    kind: PhantomData<N>,
+}
+impl<N: Integer> Primes<N> {
+    pub fn new() -> Self {
+        Self { start: 2, buffer: Vec::new(), base: Vec::new(), kind: PhantomData }
+    }
+    pub fn starting_at(n: N) -> Self {
+        Self { start: n.to_i128().max(2) as u128, buffer: Vec::new(), base: Vec::new(), kind: PhantomData }
+    }
+}
+impl<N: Integer> Default for Primes<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<N: Integer> Iterator for Primes<N> {
+    type Item = N;
+    fn next(&mut self) -> Option<N> {
+        loop {
+            if let Some(prime) = self.buffer.pop() { return N::from_i128(prime as i128) };
+            if self.start >= SIEVE_LIMIT as u128 {
+                while !prime_u128(self.start) { self.start += 1; }
+                self.start += 1;
+                return N::from_i128((self.start - 1) as i128);
+            }
+            let (lo, hi): (u64, u64) = (self.start as u64, SIEVE_LIMIT.min(self.start as u64 + SEGMENT));
+            let limit: u64 = (hi - 1).isqrt();
+            if self.base.last().is_none_or(|top| *top < limit) { self.base = small_primes(limit * 2); }
+            self.buffer = sieve_segment(lo, hi, &self.base);
+            self.buffer.reverse();
+            self.start = hi as u128;
+        }
+    }
+}
+
+fn small_primes(limit: u64) -> Vec<u64> {
+    let mut composite: Vec<bool> = vec![false; limit as usize + 1];
+    let mut result: Vec<u64> = Vec::new();
+    for n in 2..=limit {
+        if composite[n as usize] { continue };
+        result.push(n);
+        let mut multiple: u64 = n * n;
+        while multiple <= limit {
+            composite[multiple as usize] = true;
+            multiple += n;
+        }
+    }
+    result
+}
+fn sieve_segment(lo: u64, hi: u64, base: &[u64]) -> Vec<u64> {
+    let mut composite: Vec<bool> = vec![false; (hi - lo) as usize];
+    for p in base {
+        if *p > (hi - 1) / *p { break };
+        let mut multiple: u64 = (*p * *p).max(lo.div_ceil(*p) * *p);
+        while multiple < hi {
+            composite[(multiple - lo) as usize] = true;
+            multiple += *p;
+        }
+    }
+    (lo.max(2)..hi).filter(|n| !composite[(n - lo) as usize]).collect()
+}
+
+fn prime_u128(n: u128) -> bool {
+    for p in WITNESSES {
+        if n == p { return true };
+        if n.is_multiple_of(p) { return false };
+    }
+    if n < 41 * 41 { return n > 1 };
+    let (mut odd, mut twos): (u128, u32) = (n - 1, 0);
+    while odd.is_multiple_of(2) { odd /= 2; twos += 1; }
+    if !WITNESSES.iter().all(|base| strong_probable_prime(n, *base, odd, twos)) { return false };
+    if n <= u64::MAX as u128 { return true };
+    // beyond 64 bits the answer is probabilistic, with witnesses drawn from a xorshift seeded by n
+    let mut state: u128 = n ^ 0x9e37_79b9_7f4a_7c15;
+    (0..EXTRA_ROUNDS).all(|_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        strong_probable_prime(n, 2 + state % (n - 3), odd, twos)
+    })
+}
+fn strong_probable_prime(n: u128, base: u128, odd: u128, twos: u32) -> bool {
+    let mut x: u128 = pow_mod(base, odd, n);
+    if x == 1 || x == n - 1 { return true };
+    for _ in 1..twos {
+        x = mul_mod(x, x, n);
+        if x == n - 1 { return true };
+    }
+    false
+}
+pub(crate) fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (mut a, mut b): (u128, u128) = (a % m, b % m);
+    if m <= u64::MAX as u128 { return a * b % m };
+    let mut result: u128 = 0;
+    while b > 0 {
+        if b & 1 == 1 { result = add_mod(result, a, m); }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+pub(crate) fn pow_mod(base: u128, exp: u128, m: u128) -> u128 {
+    let (mut base, mut exp, mut result): (u128, u128, u128) = (base % m, exp, 1 % m);
+    while exp > 0 {
+        if exp & 1 == 1 { result = mul_mod(result, base, m); }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+pub fn gcd<R: RealArithmetic>(a: R, b: R) -> R {
+    let (mut a, mut b): (R, R) = (a, b);
+    while b != R::ZERO { (a, b) = (b, a % b); }
+    if a < R::ZERO { -a } else { a }
+}
+// (g, x, y) with g = gcd(a, b) = ax + by
+pub fn ext_gcd<R: RealArithmetic>(a: R, b: R) -> (R, R, R) {
+    let (mut r0, mut r1): (R, R) = (a, b);
+    let (mut x0, mut x1, mut y0, mut y1): (R, R, R, R) = (R::ONE, R::ZERO, R::ZERO, R::ONE);
+    while r1 != R::ZERO {
+        let quotient: R = r0 / r1;
+        (r0, r1) = (r1, r0 - quotient * r1);
+        (x0, x1) = (x1, x0 - quotient * x1);
+        (y0, y1) = (y1, y0 - quotient * y1);
+    }
+    if r0 < R::ZERO { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+// None when the lcm does not fit in the integer type
+pub fn lcm<R: CheckedArithmetic>(a: R, b: R) -> Option<R> {
+    if a == R::ZERO || b == R::ZERO { return Some(R::ZERO) };
+    let result: R = (a / gcd(a, b)).checked_mul(b)?;
+    if result < R::ZERO { result.checked_neg() } else { Some(result) }
+}
+pub fn mod_inverse<R: RealArithmetic>(a: R, m: R) -> Option<R> {
+    let (g, x, _): (R, R, R) = ext_gcd(reduce(a, m), m);
+    if g != R::ONE { return None };
+    Some(reduce(x, m))
+}
+// solves x = r (mod m) for every (r, m), returning the residue and the lcm of the moduli,
+// or None when the congruences contradict each other or the lcm does not fit
+pub fn crt<N: Integer>(congruences: &[(N, N)]) -> Option<(N, N)> {
+    let (mut residue, mut modulus): (i128, i128) = (0, 1);
+    for (r, m) in congruences {
+        let m: i128 = positive(*m) as i128;
+        let g: i128 = gcd(modulus, m);
+        let gap: i128 = r.to_i128().rem_euclid(m) - residue;
+        if gap % g != 0 { return None };
+        let step: i128 = m / g;
+        let scale: i128 = mod_inverse(modulus / g, step)?;
+        let t: u128 = mul_mod(reduce(gap / g, step) as u128, scale as u128, step as u128);
+        let next: i128 = modulus.checked_mul(step)?;
+        N::from_i128(next)?;
+        // residue + modulus * t stays below the new modulus, so neither step overflows
+        residue += modulus * t as i128;
+        modulus = next;
+    }
+    Some((N::from_i128(residue)?, N::from_i128(modulus)?))
+}
+fn reduce<R: RealArithmetic>(a: R, m: R) -> R {
+    let rest: R = a % m;
+    if rest < R::ZERO { rest + m } else { rest }
+}
+
+pub fn mod_pow<N: Integer>(base: N, exp: N, m: N) -> N {
+    let modulus: u128 = positive(m);
+    let (base, exp): (u128, i128) = if exp < N::ZERO {
+        match mod_inverse(base.to_i128(), modulus as i128) {
+            Some(inverse) => (inverse as u128, -exp.to_i128()),
+            None => panic!("negative power of a value with no inverse"),
+        }
+    } else {
+        (residue(base, modulus), exp.to_i128())
+    };
+    narrow(pow_mod(base, exp as u128, modulus) as i128)
+}
+// the smallest square root of a modulo m, by Tonelli-Shanks when m is prime
+pub fn mod_sqrt<N: Integer>(a: N, m: N) -> Option<N> {
+    if !is_prime(m) { return mod_sqrts(a, m).first().copied() };
+    let p: u128 = positive(m);
+    let root: u128 = tonelli(residue(a, p), p)?;
+    Some(narrow(root.min(p - root) as i128))
+}
+// every square root of a modulo m in increasing order, lifted to each prime power and joined by CRT
+pub fn mod_sqrts<N: Integer>(a: N, m: N) -> Vec<N> {
+    let modulus: u128 = positive(m);
+    let a: u128 = residue(a, modulus);
+    let (mut roots, mut joined): (Vec<u128>, u128) = (vec![0], 1);
+    if modulus == 1 { return vec![N::ZERO] };
+    for (p, power) in factor_u128(modulus) {
+        let local: u128 = p.pow(power);
+        let lifted: Vec<u128> = prime_power_sqrts(a % local, p, power);
+        let mut next: Vec<u128> = Vec::new();
+        for x in &roots {
+            for y in &lifted {
+                if let Some((z, _)) = crt(&[(*x as i128, joined as i128), (*y as i128, local as i128)]) { next.push(z as u128); }
+            }
+        }
+        roots = next;
+        joined *= local;
+    }
+    roots.sort();
+    roots.into_iter().map(|root| narrow(root as i128)).collect()
+}
+fn tonelli(a: u128, p: u128) -> Option<u128> {
+    if a == 0 || p == 2 { return Some(a) };
+    if pow_mod(a, (p - 1) / 2, p) != 1 { return None };
+    let (mut odd, mut twos): (u128, u32) = (p - 1, 0);
+    while odd.is_multiple_of(2) { odd /= 2; twos += 1; }
+    let mut z: u128 = 2;
+    while pow_mod(z, (p - 1) / 2, p) != p - 1 { z += 1; }
+    let (mut c, mut t, mut root): (u128, u128, u128) = (pow_mod(z, odd, p), pow_mod(a, odd, p), pow_mod(a, odd.div_ceil(2), p));
+    while t != 1 {
+        let (mut least, mut square): (u32, u128) = (0, t);
+        while square != 1 { square = mul_mod(square, square, p); least += 1; }
+        let b: u128 = pow_mod(c, 1 << (twos - least - 1), p);
+        twos = least;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        root = mul_mod(root, b, p);
+    }
+    Some(root)
+}
+// the roots of x^2 = a (mod p^k); a = p^v b needs v even, and then x = p^(v/2) y with y^2 = b
+fn prime_power_sqrts(a: u128, p: u128, k: u32) -> Vec<u128> {
+    let local: u128 = p.pow(k);
+    if a == 0 {
+        let step: u128 = p.pow(k.div_ceil(2));
+        return (0..local / step).map(|t| t * step).collect();
+    }
+    let (mut unit, mut v): (u128, u32) = (a, 0);
+    while unit.is_multiple_of(p) { unit /= p; v += 1; }
+    if v % 2 == 1 { return Vec::new() };
+    let (inner, half): (u128, u128) = (p.pow(k - v), p.pow(v / 2));
+    let mut result: Vec<u128> = Vec::new();
+    for y in unit_sqrts(unit % inner, p, k - v) {
+        for t in 0..half { result.push(half * (y + t * inner) % local); }
+    }
+    result
+}
+// the roots of x^2 = b (mod p^k) for a unit b, by Hensel lifting
+fn unit_sqrts(b: u128, p: u128, k: u32) -> Vec<u128> {
+    if p == 2 {
+        let mut roots: Vec<u128> = (1..(1u128 << k.min(3))).step_by(2).collect();
+        let mut bits: u32 = k.min(3);
+        roots.retain(|r| r * r % (1 << bits) == b % (1 << bits));
+        // each root mod 2^j leaves two candidates mod 2^(j + 1)
+        while bits < k {
+            bits += 1;
+            let local: u128 = 1 << bits;
+            let mut next: Vec<u128> = Vec::new();
+            for r in &roots {
+                for c in [*r, *r + (local >> 1)] {
+                    if mul_mod(c, c, local) == b % local && !next.contains(&c) { next.push(c); }
+                }
+            }
+            roots = next;
+        }
+        return roots;
+    }
+    let mut root: u128 = match tonelli(b % p, p) {
+        Some(root) => root,
+        None => return Vec::new(),
+    };
+    let mut local: u128 = p;
+    for _ in 1..k {
+        local *= p;
+        // r - (r^2 - b) / 2r keeps the root while gaining a power of p
+        let error: u128 = (mul_mod(root, root, local) + local - b % local) % local;
+        let inverse: u128 = mod_inverse(mul_mod(2, root, local) as i128, local as i128).unwrap_or(0) as u128;
+        root = (root + local - mul_mod(error, inverse, local)) % local;
+    }
+    if root == local - root { vec![root] } else { vec![root, local - root] }
+}
+pub fn multiplicative_order<N: Integer>(a: N, m: N) -> Option<N> {
+    let modulus: u128 = positive(m);
+    let a: u128 = residue(a, modulus);
+    if gcd_u128(a, modulus) != 1 { return None };
+    Some(narrow(order_u128(a, modulus) as i128))
+}
+// the smallest generator of the units modulo m, which exist only for 1, 2, 4, p^k and 2p^k
+pub fn primitive_root<N: Integer>(m: N) -> Option<N> {
+    let modulus: u128 = positive(m);
+    if modulus <= 4 { return N::from_i128(modulus as i128 - 1) };
+    let odd: Vec<(i128, u32)> = factor(modulus as i128).into_iter().filter(|(p, _)| *p != 2).collect();
+    if odd.len() != 1 || modulus.is_multiple_of(4) { return None };
+    let phi: u128 = totient(modulus as i128) as u128;
+    let primes: Vec<(i128, u32)> = factor(phi as i128);
+    let mut g: u128 = 2;
+    loop {
+        if gcd_u128(g, modulus) == 1 && primes.iter().all(|(q, _)| pow_mod(g, phi / *q as u128, modulus) != 1) {
+            return N::from_i128(g as i128);
+        }
+        g += 1;
+    }
+}
+// the smallest x >= 0 with g^x = h (mod m), by Pohlig-Hellman over the order of g
+pub fn discrete_log<N: Integer>(g: N, h: N, m: N) -> Option<N> {
+    let modulus: u128 = positive(m);
+    let (g, h): (u128, u128) = (residue(g, modulus), residue(h, modulus));
+    if let Some(x) = small_log(g, h, modulus) { return N::from_i128(x as i128) };
+    let (target, reduced, shift): (u128, u128, u128) = reduce_log(g, h, modulus)?;
+    let (g, h): (u128, u128) = (g % reduced, target);
+    if reduced == 1 { return N::from_i128(shift as i128) };
+    if gcd_u128(h, reduced) != 1 { return None };
+    let order: u128 = order_u128(g, reduced);
+    let inverse: u128 = mod_inverse(g as i128, reduced as i128)? as u128;
+    let mut congruences: Vec<(i128, i128)> = Vec::new();
+    for (q, power) in factor(order as i128) {
+        let q: u128 = q as u128;
+        let base: u128 = pow_mod(g, order / q, reduced);
+        let (mut x, mut q_k): (u128, u128) = (0, 1);
+        for _ in 0..power {
+            let shifted: u128 = mul_mod(pow_mod(inverse, x, reduced), h, reduced);
+            let target: u128 = pow_mod(shifted, order / (q_k * q), reduced);
+            x += baby_giant(base, target, reduced, q)? * q_k;
+            q_k *= q;
+        }
+        congruences.push((x as i128, q_k as i128));
+    }
+    let (x, _): (i128, i128) = crt(&congruences)?;
+    if pow_mod(g, x as u128, reduced) != h { return None };
+    N::from_i128(x + shift as i128)
+}
+// the same search by baby-step giant-step alone, in O(sqrt(m)) time and memory
+pub fn discrete_log_bsgs<N: Integer>(g: N, h: N, m: N) -> Option<N> {
+    let modulus: u128 = positive(m);
+    let (g, h): (u128, u128) = (residue(g, modulus), residue(h, modulus));
+    if let Some(x) = small_log(g, h, modulus) { return N::from_i128(x as i128) };
+    let (target, reduced, shift): (u128, u128, u128) = reduce_log(g, h, modulus)?;
+    if reduced == 1 { return N::from_i128(shift as i128) };
+    N::from_i128((baby_giant(g % reduced, target, reduced, reduced)? + shift) as i128)
+}
+// every exponent below the bit length of m, which covers the ones reduce_log skips
+fn small_log(g: u128, h: u128, m: u128) -> Option<u128> {
+    let mut running: u128 = 1 % m;
+    for x in 0..=128 - m.leading_zeros() as u128 {
+        if running == h { return Some(x) };
+        running = mul_mod(running, g, m);
+    }
+    None
+}
+// divides out d = gcd(g, m) until g is a unit, turning g^x = h (mod m) into
+// g^(x - shift) = target (mod reduced); None when some d does not divide h
+fn reduce_log(g: u128, h: u128, m: u128) -> Option<(u128, u128, u128)> {
+    let (mut h, mut m, mut shift, mut scale): (u128, u128, u128, u128) = (h, m, 0, 1 % m);
+    loop {
+        let d: u128 = gcd_u128(g, m);
+        if d == 1 { break };
+        if !h.is_multiple_of(d) { return None };
+        (h, m, shift) = (h / d, m / d, shift + 1);
+        scale = mul_mod(scale, g / d, m);
+    }
+    if m == 1 { return Some((0, 1, shift)) };
+    let inverse: u128 = mod_inverse(scale as i128, m as i128)? as u128;
+    Some((mul_mod(h, inverse, m), m, shift))
+}
+
+pub fn jacobi<N: Integer>(a: N, n: N) -> N {
+    let n: i128 = n.to_i128();
+    if n <= 0 || n % 2 == 0 { panic!("the Jacobi symbol needs a positive odd modulus") };
+    narrow(jacobi_i128(a.to_i128(), n))
+}
+pub fn legendre<N: Integer>(a: N, p: N) -> N {
+    if p.to_i128() == 2 || !is_prime(p) { panic!("the Legendre symbol needs an odd prime") };
+    jacobi(a, p)
+}
+pub fn kronecker<N: Integer>(a: N, n: N) -> N {
+    let (a, mut n): (i128, i128) = (a.to_i128(), n.to_i128());
+    if n == 0 { return narrow((a == 1 || a == -1) as i128) };
+    let mut result: i128 = 1;
+    if n < 0 {
+        n = -n;
+        if a < 0 { result = -result; }
+    }
+    while n % 2 == 0 {
+        n /= 2;
+        result *= match a.rem_euclid(8) {
+            1 | 7 => 1,
+            3 | 5 => -1,
+            _ => 0,
+        };
+    }
+    narrow(result * jacobi_i128(a, n))
+}
+// every value x^2 mod m, in increasing order
+pub fn quadratic_residues<N: Integer>(m: N) -> Vec<N> {
+    let modulus: u128 = positive(m);
+    let mut seen: Vec<bool> = vec![false; modulus as usize];
+    for x in 0..=modulus / 2 { seen[mul_mod(x, x, modulus) as usize] = true; }
+    (0..modulus).filter(|r| seen[*r as usize]).map(|r| narrow(r as i128)).collect()
+}
+// a primitive solution of x^2 + d y^2 = m with x, y >= 0, for 0 < d < m; a composite m
+// costs a factorization plus one attempt for each of the 2^k square roots of -d
+pub fn cornacchia<N: Integer>(d: N, m: N) -> Option<(N, N)> {
+    let (d, m): (i128, i128) = (d.to_i128(), positive(m) as i128);
+    if d <= 0 || d >= m { panic!("cornacchia needs 0 < d < m") };
+    let roots: Vec<i128> = mod_sqrts(-d, m).into_iter().filter(|r| *r <= m / 2).collect();
+    for root in roots {
+        let (mut a, mut b): (i128, i128) = (m, root);
+        while b * b >= m { (a, b) = (b, a % b); }
+        let rest: i128 = m - b * b;
+        if rest % d != 0 { continue };
+        let y: i128 = (rest / d).isqrt();
+        if y * y == rest / d && gcd(b, y) == 1 { return Some((narrow(b), narrow(y))) };
+    }
+    None
+}
+// a^2 + b^2 = n with 0 <= a <= b, assembled as a product of Gaussian integers
+pub fn sum_of_two_squares<N: Integer>(n: N) -> Option<(N, N)> {
+    let n: i128 = n.to_i128();
+    if n < 0 { return None };
+    if n == 0 { return Some((N::ZERO, N::ZERO)) };
+    let mut product: Comp<i128> = Comp::ONE;
+    for (p, power) in factor(n) {
+        let prime: Comp<i128> = match p % 4 {
+            2 => Comp::new(1, 1),
+            3 if power % 2 == 1 => return None,
+            3 => Comp::nre(p),
+            _ => {
+                let (a, b): (i128, i128) = cornacchia(1, p)?;
+                Comp::new(a, b)
+            },
+        };
+        let times: u32 = if p % 4 == 3 { power / 2 } else { power };
+        for _ in 0..times { product *= prime; }
+    }
+    let (a, b): (i128, i128) = (product.r.abs(), product.i.abs());
+    Some((narrow(a.min(b)), narrow(a.max(b))))
+}
+
+fn jacobi_i128(a: i128, n: i128) -> i128 {
+    let (mut a, mut n): (i128, i128) = (a.rem_euclid(n), n);
+    let mut result: i128 = 1;
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 { result = -result; }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 { result = -result; }
+        a %= n;
+    }
+    if n == 1 { result } else { 0 }
+}
+
+pub fn isqrt<N: Integer>(n: N) -> N {
+    iroot(n, 2)
+}
+pub fn icbrt<N: Integer>(n: N) -> N {
+    iroot(n, 3)
+}
+// the k-th root rounded toward zero, which odd k extend to negative n
+pub fn iroot<N: Integer>(n: N, k: u32) -> N {
+    if k == 0 { panic!("zeroth root") };
+    let wide: i128 = n.to_i128();
+    if wide < 0 && k.is_multiple_of(2) { panic!("even root of a negative number") };
+    let root: i128 = iroot_u128(wide.unsigned_abs(), k) as i128;
+    narrow(if wide < 0 { -root } else { root })
+}
+pub fn exact_root<N: Integer>(n: N, k: u32) -> Option<N> {
+    if n < N::ZERO && k.is_multiple_of(2) { return None };
+    let root: N = iroot(n, k);
+    if root.to_i128().checked_pow(k)? == n.to_i128() { Some(root) } else { None }
+}
+// the base and the largest exponent above one with base^exponent = n, if there is one
+pub fn is_perfect_power<N: Integer>(n: N) -> Option<(N, u32)> {
+    let wide: i128 = n.to_i128();
+    if wide.unsigned_abs() < 2 { return None };
+    let bits: u32 = 128 - wide.unsigned_abs().leading_zeros();
+    for k in (2..bits).rev() {
+        if let Some(root) = exact_root(n, k) { return Some((root, k)) };
+    }
+    None
+}
+fn iroot_u128(n: u128, k: u32) -> u128 {
+    if n < 2 || k == 1 { return n };
+    // f64 keeps 53 bits, so near 2^127 the estimate can be thousands off; raise it past the root
+    // and let integer Newton steps, which decrease monotonically from above, land on the floor
+    let mut root: u128 = ((n as f64).powf(1.0 / k as f64) as u128).max(1);
+    while root.checked_pow(k).is_some_and(|power| power <= n) { root *= 2; }
+    loop {
+        let below: u128 = root.checked_pow(k - 1).map_or(0, |power| n / power);
+        let next: u128 = ((k - 1) as u128 * root + below) / k as u128;
+        if next >= root { break };
+        root = next;
+    }
+    while root.checked_pow(k).is_none_or(|power| power > n) { root -= 1; }
+    root
+}
+
+// the smallest positive solution of x^2 - d y^2 = 1, read off the period of √d
+pub fn pell<N: Integer>(d: N) -> Option<(N, N)> {
+    let (x, y): (i128, i128) = pell_unit(d.to_i128())?;
+    Some((N::from_i128(x)?, N::from_i128(y)?))
+}
+// one solution with x, y >= 0 from each class of x^2 - d y^2 = n, searched within Nagell's bounds;
+// None, like pell, when d is not a positive non-square or the unit or bound on y does not fit in an i128
+pub fn pell_fundamental<N: Integer>(d: N, n: N) -> Option<Vec<(N, N)>> {
+    let (d, n): (i128, i128) = (d.to_i128(), n.to_i128());
+    if d <= 0 || exact_root(d, 2).is_some() { return None };
+    if n == 0 { return Some(vec![(N::ZERO, N::ZERO)]) };
+    // the trivial class is the only one of norm 1
+    if n == 1 { return Some(vec![(N::ONE, N::ZERO)]) };
+    let (x1, y1): (i128, i128) = pell_unit(d)?;
+    let scale: i128 = if n > 0 { x1.checked_add(1)?.checked_mul(2)? } else { 2 * (x1 - 1) };
+    let limit: i128 = y1.checked_mul(y1)?.checked_mul(n.abs())?;
+    let mut result: Vec<(N, N)> = Vec::new();
+    let mut y: i128 = 0;
+    // once y^2 scale overflows it is past the limit, which did fit
+    while let Some(lhs) = (y * y).checked_mul(scale) {
+        if lhs > limit { break };
+        let square: i128 = n.checked_add(d.checked_mul(y * y)?)?;
+        if let Some(x) = exact_root(square, 2) {
+            if let (Some(x), Some(y)) = (N::from_i128(x), N::from_i128(y)) { result.push((x, y)); }
+        }
+        y += 1;
+    }
+    Some(result)
+}
+
+// every solution of x^2 - d y^2 = n with x, y >= 0 in increasing order, until they overflow
+#[derive(Clone, Debug)]
+pub struct PellSolutions<N: Integer> {
+    d: i128,
+    unit: (i128, i128),
+    heads: Vec<(i128, i128)>,
+    last: Option<(i128, i128)>,
+    kind: PhantomData<N>,
+}
+impl<N: Integer> PellSolutions<N> {
+    // None when d is not a positive non-square or the fundamental unit does not fit in an i128
+    pub fn new(d: N, n: N) -> Option<Self> {
+        let fundamental: Vec<(N, N)> = pell_fundamental(d, n)?;
+        let unit: (i128, i128) = pell_unit(d.to_i128())?;
+        let norm: i128 = n.to_i128();
+        let mut heads: Vec<(i128, i128)> = Vec::new();
+        // the representatives with x + y√d > 0, whose orbits under the unit cover every class
+        for (x, y) in fundamental {
+            let (x, y): (i128, i128) = (x.to_i128(), y.to_i128());
+            heads.push((x, y));
+            if norm > 0 && y != 0 { heads.push((x, -y)); }
+            if norm < 0 && x != 0 { heads.push((-x, y)); }
+        }
+        Some(Self { d: d.to_i128(), unit, heads, last: None, kind: PhantomData })
+    }
+    fn advance(&self, (x, y): (i128, i128)) -> Option<(i128, i128)> {
+        let (x1, y1): (i128, i128) = self.unit;
+        Some((
+            x1.checked_mul(x)?.checked_add(self.d.checked_mul(y1)?.checked_mul(y)?)?,
+            x1.checked_mul(y)?.checked_add(y1.checked_mul(x)?)?,
+        ))
+    }
+}
+impl<N: Integer> Iterator for PellSolutions<N> {
+    type Item = (N, N);
+    fn next(&mut self) -> Option<(N, N)> {
+        loop {
+            for indx in 0..self.heads.len() {
+                while self.heads[indx].0 < 0 || self.heads[indx].1 < 0 {
+                    self.heads[indx] = self.advance(self.heads[indx])?;
+                }
+            }
+            let indx: usize = (0..self.heads.len()).min_by_key(|indx| self.heads[*indx].0)?;
+            let found: (i128, i128) = self.heads[indx];
+            self.heads[indx] = self.advance(found)?;
+            if self.last == Some(found) { continue };
+            self.last = Some(found);
+            return Some((N::from_i128(found.0)?, N::from_i128(found.1)?));
+        }
+    }
+}
+
+// all solutions of ax + by = c are (x + k dx, y + k dy), with 0 <= x < |dx|
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearSolution<N: Integer> {
+    pub x: N,
+    pub y: N,
+    pub dx: N,
+    pub dy: N,
+}
+impl<N: Integer> LinearSolution<N> {
+    pub fn at(&self, k: N) -> (N, N) {
+        (self.x + k * self.dx, self.y + k * self.dy)
+    }
+}
+pub fn linear_diophantine<N: Integer>(a: N, b: N, c: N) -> Option<LinearSolution<N>> {
+    let (a, b, c): (i128, i128, i128) = (a.to_i128(), b.to_i128(), c.to_i128());
+    // with a = b = 0 every pair solves 0 = 0, which no one-parameter family describes
+    if a == 0 && b == 0 { return None };
+    let (g, s, t): (i128, i128, i128) = ext_gcd(a, b);
+    if c % g != 0 { return None };
+    let (dx, dy): (i128, i128) = (b / g, -a / g);
+    let (mut x, mut y): (i128, i128) = (s * (c / g), t * (c / g));
+    if dx != 0 {
+        let k: i128 = x.div_euclid(dx.abs()) * dx.signum();
+        (x, y) = (x - k * dx, y - k * dy);
+    }
+    Some(LinearSolution { x: N::from_i128(x)?, y: N::from_i128(y)?, dx: N::from_i128(dx)?, dy: N::from_i128(dy)? })
+}
+
+fn pell_unit(d: i128) -> Option<(i128, i128)> {
+    if d <= 0 || exact_root(d, 2).is_some() { return None };
+    let period: ContinuedFraction<i128> = ContinuedFraction::of_sqrt(d);
+    let last: usize = period.terms.len() - 2;
+    let (mut h1, mut h2, mut k1, mut k2): (i128, i128, i128, i128) = (1, 0, 0, 1);
+    for term in &period.terms[..=last] {
+        (h1, h2) = (term.checked_mul(h1)?.checked_add(h2)?, h1);
+        (k1, k2) = (term.checked_mul(k1)?.checked_add(k2)?, k1);
+    }
+    // an odd period lands on x^2 - d y^2 = -1, which squares to the unit
+    if last % 2 == 1 { return Some((h1, k1)) };
+    Some((h1.checked_mul(h1)?.checked_add(d.checked_mul(k1)?.checked_mul(k1)?)?, 2i128.checked_mul(h1)?.checked_mul(k1)?))
+}
+
+fn positive<N: Integer>(m: N) -> u128 {
+    if m <= N::ZERO { panic!("the modulus must be positive") };
+    m.to_i128() as u128
+}
+fn residue<N: Integer>(a: N, m: u128) -> u128 {
+    a.to_i128().rem_euclid(m as i128) as u128
+}
+fn order_u128(a: u128, m: u128) -> u128 {
+    let mut order: u128 = totient(m as i128) as u128;
+    for (q, _) in factor(order as i128) {
+        let q: u128 = q as u128;
+        while order.is_multiple_of(q) && pow_mod(a, order / q, m) == 1 { order /= q; }
+    }
+    order
+}
+// x < bound with g^x = h (mod m), if there is one
+fn baby_giant(g: u128, h: u128, m: u128, bound: u128) -> Option<u128> {
+    let steps: u128 = bound.isqrt() + 1;
+    let mut table: HashMap<u128, u128> = HashMap::new();
+    let mut running: u128 = 1 % m;
+    for j in 0..steps {
+        table.entry(running).or_insert(j);
+        running = mul_mod(running, g, m);
+    }
+    let stride: u128 = pow_mod(mod_inverse(g as i128, m as i128)? as u128, steps, m);
+    let mut gamma: u128 = h;
+    for i in 0..steps {
+        if let Some(j) = table.get(&gamma) { return Some(i * steps + j) };
+        gamma = mul_mod(gamma, stride, m);
+    }
+    None
+}
+
+const TRIAL_LIMIT: u64 = 1000;
+const RHO_STEPS: u128 = 1 << 18;
+const SMOOTHNESS: u64 = 100000;
+
+// prime-exponent pairs of |n| in increasing order; unsigned values can go through i128
+// trial division, then Brent's rho, Pollard's p - 1 and Lenstra's curves; a semiprime
+// near 2^64 splits in milliseconds, while two 40-bit factors take seconds in release and
+// two 64-bit factors over a minute
+pub fn factor<N: Integer>(n: N) -> Vec<(N, u32)> {
+    let n: i128 = n.to_i128();
+    if n == 0 { panic!("zero has no factorization") };
+    factor_u128(n.unsigned_abs()).into_iter().map(|(p, power)| (narrow(p as i128), power)).collect()
+}
+// entry points for unsigned values, which Integer cannot carry past i64::MAX or i128::MAX
+pub fn is_prime_u64(n: u64) -> bool {
+    prime_u128(n as u128)
+}
+pub fn is_prime_u128(n: u128) -> bool {
+    prime_u128(n)
+}
+pub fn factor_u64(n: u64) -> Vec<(u64, u32)> {
+    factor_u128(n as u128).into_iter().map(|(p, power)| (p as u64, power)).collect()
+}
+pub fn factor_u128(n: u128) -> Vec<(u128, u32)> {
+    if n == 0 { panic!("zero has no factorization") };
+    let mut primes: Vec<u128> = Vec::new();
+    let mut rest: u128 = n;
+    for p in small_primes(TRIAL_LIMIT) {
+        while rest.is_multiple_of(p as u128) {
+            rest /= p as u128;
+            primes.push(p as u128);
+        }
+    }
+    let mut pending: Vec<u128> = if rest > 1 { vec![rest] } else { Vec::new() };
+    while let Some(part) = pending.pop() {
+        if prime_u128(part) { primes.push(part); continue };
+        let split: u128 = find_factor(part);
+        pending.push(split);
+        pending.push(part / split);
+    }
+    primes.sort();
+    let mut result: Vec<(u128, u32)> = Vec::new();
+    for p in primes {
+        match result.last_mut() {
+            Some((last, power)) if *last == p => *power += 1,
+            _ => result.push((p, 1)),
+        }
+    }
+    result
+}
+pub fn divisors<N: Integer>(n: N) -> Vec<N> {
+    let mut result: Vec<i128> = vec![1];
+    for (p, power) in factor(n) {
+        let mut next: Vec<i128> = Vec::new();
+        for d in &result {
+            let mut term: i128 = *d;
+            for _ in 0..=power {
+                next.push(term);
+                term *= p.to_i128();
+            }
+        }
+        result = next;
+    }
+    result.sort();
+    result.into_iter().map(narrow).collect()
+}
+pub fn divisor_count<N: Integer>(n: N) -> N {
+    narrow(factor(n).iter().map(|(_, power)| *power as i128 + 1).product())
+}
+pub fn divisor_sum<N: Integer>(n: N) -> N {
+    narrow(factor(n).iter().map(|(p, power)| {
+        let p: i128 = p.to_i128();
+        (p.pow(power + 1) - 1) / (p - 1)
+    }).product())
+}
+pub fn totient<N: Integer>(n: N) -> N {
+    narrow(factor(n).iter().map(|(p, power)| {
+        let p: i128 = p.to_i128();
+        p.pow(power - 1) * (p - 1)
+    }).product())
+}
+pub fn mobius<N: Integer>(n: N) -> N {
+    let factors: Vec<(N, u32)> = factor(n);
+    if factors.iter().any(|(_, power)| *power > 1) { return N::ZERO };
+    if factors.len().is_multiple_of(2) { N::ONE } else { -N::ONE }
+}
+
+fn narrow<N: Integer>(wide: i128) -> N {
+    match N::from_i128(wide) {
+        Some(n) => n,
+        None => panic!("{} does not fit in the integer type", wide),
+    }
+}
+fn gcd_u128(a: u128, b: u128) -> u128 {
+    let (mut a, mut b): (u128, u128) = (a, b);
+    while b != 0 { (a, b) = (b, a % b); }
+    a
+}
+// a nontrivial factor of a composite n with no small prime factors
+fn find_factor(n: u128) -> u128 {
+    for c in 1..4 {
+        if let Some(found) = brent(n, c) { return found };
+    }
+    if let Some(found) = pollard_pm1(n) { return found };
+    let mut seed: u128 = n ^ 0x2545_f491_4f6c_dd1d;
+    let mut curves: u64 = 0;
+    loop {
+        if let Some(found) = lenstra(n, &mut seed, 2000 + 100 * curves) { return found };
+        curves += 1;
+    }
+}
+fn brent(n: u128, c: u128) -> Option<u128> {
+    let step = |x: u128| -> u128 { add_mod(mul_mod(x, x, n), c, n) };
+    let (mut y, mut x, mut saved): (u128, u128, u128) = (2, 2, 2);
+    let (mut power, mut product, mut divisor): (u128, u128, u128) = (1, 1, 1);
+    let mut taken: u128 = 0;
+    while divisor == 1 {
+        x = y;
+        for _ in 0..power { y = step(y); }
+        let mut done: u128 = 0;
+        while done < power && divisor == 1 {
+            saved = y;
+            for _ in 0..128.min(power - done) {
+                y = step(y);
+                product = mul_mod(product, x.abs_diff(y), n);
+            }
+            divisor = gcd_u128(product, n);
+            done += 128;
+        }
+        taken += power;
+        power *= 2;
+        if taken > RHO_STEPS { return None };
+    }
+    if divisor == n {
+        // the batch overshot, so retrace it one step at a time
+        loop {
+            saved = step(saved);
+            divisor = gcd_u128(x.abs_diff(saved), n);
+            if divisor > 1 { break };
+        }
+    }
+    if divisor == n { None } else { Some(divisor) }
+}
+pub(crate) fn pollard_pm1(n: u128) -> Option<u128> {
+    let mut a: u128 = 2;
+    for p in small_primes(SMOOTHNESS) {
+        let mut power: u64 = p;
+        while power <= SMOOTHNESS / p { power *= p; }
+        a = pow_mod(a, power as u128, n);
+    }
+    let divisor: u128 = gcd_u128(add_mod(a, n - 1, n), n) % n;
+    if divisor > 1 && divisor < n { Some(divisor) } else { None }
+}
+
+// Lenstra's method on y^2 = x^3 + ax + b in affine coordinates, where a failed
+// inversion modulo n is exactly what reveals a factor
+type Point = Option<(u128, u128)>;
+fn lenstra(n: u128, seed: &mut u128, bound: u64) -> Option<u128> {
+    let mut draw = || -> u128 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        *seed % n
+    };
+    let (a, x, y): (u128, u128, u128) = (draw(), draw(), draw());
+    let mut point: Point = Some((x, y));
+    for p in small_primes(bound) {
+        let mut power: u64 = p;
+        while power <= bound / p { power *= p; }
+        point = match curve_mul(point, power as u128, a, n) {
+            Ok(next) => next,
+            Err(divisor) if divisor < n => return Some(divisor),
+            Err(_) => return None,
+        };
+        if point.is_none() { break };
+    }
+    None
+}
+fn curve_mul(point: Point, k: u128, a: u128, n: u128) -> Result<Point, u128> {
+    let (mut result, mut base, mut k): (Point, Point, u128) = (None, point, k);
+    while k > 0 {
+        if k & 1 == 1 { result = curve_add(result, base, a, n)?; }
+        base = curve_add(base, base, a, n)?;
+        k >>= 1;
+    }
+    Ok(result)
+}
+fn curve_add(lhs: Point, rhs: Point, a: u128, n: u128) -> Result<Point, u128> {
+    let ((x1, y1), (x2, y2)): ((u128, u128), (u128, u128)) = match (lhs, rhs) {
+        (None, _) => return Ok(rhs),
+        (_, None) => return Ok(lhs),
+        (Some(p), Some(q)) => (p, q),
+    };
+    let slope: u128 = if x1 == x2 {
+        if add_mod(y1, y2, n) == 0 { return Ok(None) };
+        let top: u128 = add_mod(mul_mod(3, mul_mod(x1, x1, n), n), a, n);
+        mul_mod(top, inverse_or_factor(add_mod(y1, y1, n), n)?, n)
+    } else {
+        mul_mod(add_mod(y2, n - y1, n), inverse_or_factor(add_mod(x2, n - x1, n), n)?, n)
+    };
+    let x3: u128 = add_mod(mul_mod(slope, slope, n), n - add_mod(x1, x2, n), n);
+    let y3: u128 = add_mod(mul_mod(slope, add_mod(x1, n - x3, n), n), n - y1, n);
+    Ok(Some((x3 % n, y3 % n)))
+}
+pub(crate) fn inverse_or_factor(value: u128, n: u128) -> Result<u128, u128> {
+    // the Bezout coefficients alternate in sign, so keep their magnitudes and the sign of the newer one
+    let (mut r0, mut r1): (u128, u128) = (n, value % n);
+    let (mut s0, mut s1): (u128, u128) = (0, 1);
+    let mut negative: bool = false;
+    while r1 != 0 {
+        let quotient: u128 = r0 / r1;
+        (r0, r1) = (r1, r0 % r1);
+        (s0, s1) = (s1, s0 + quotient * s1);
+        negative = !negative;
+    }
+    if r0 != 1 { return Err(r0) };
+    Ok(if negative { s0 % n } else { (n - s0 % n) % n })
+}
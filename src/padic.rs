@@ -0,0 +1,209 @@
+use std::ops::{
+    Neg, Add, Sub, Mul, Div,
+    AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::rules::*;
+use crate::alg::Poly;
+use crate::rat::Rat;
+use std::fmt;
+
+// P^v * u, where the unit u is known modulo P^K.
+/// ```compile_fail
+/// use basemath::padic::PAdic;
+/// let _ = PAdic::<2, 64>::modulus();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PAdic<const P: u64, const K: u32 = 8> {
+    pub v: i64,
+    pub u: u64,
+}
+impl<const P: u64, const K: u32> PAdic<P, K> {
+    pub fn new(v: i64, u: u64) -> Self {
+        Self::build(v, u as u128)
+    }
+    pub fn from_int(n: i64) -> Self {
+        Self::build(0, (n as i128).rem_euclid(Self::modulus() as i128) as u128)
+    }
+    pub fn from_rat<R: RealArithmetic + Into<i128>>(q: Rat<R>) -> Self {
+        let (n, d): (i128, i128) = (q.n.into(), q.d.into());
+        if d == 0 { panic!("cannot convert a rational with zero denominator") };
+        if n == 0 { return Self::ZERO };
+        let (vn, un): (i64, i128) = split_power(n, P as i128);
+        let (vd, ud): (i64, i128) = split_power(d, P as i128);
+        let modulus: i128 = Self::modulus() as i128;
+        let top: Self = Self::build(vn, un.rem_euclid(modulus) as u128);
+        let bottom: Self = Self::build(vd, ud.rem_euclid(modulus) as u128);
+        top / bottom
+    }
+    pub fn modulus() -> u64 {
+        const { assert!(P.checked_pow(K).is_some(), "P^K does not fit in a u64") };
+        P.pow(K)
+    }
+    pub fn valuation(self) -> Option<i64> {
+        if self.u == 0 { None } else { Some(self.v) }
+    }
+    pub fn digits(self) -> Vec<u64> {
+        let mut result: Vec<u64> = Vec::new();
+        let mut running: u64 = self.u;
+        for _ in 0..K {
+            result.push(running % P);
+            running /= P;
+        }
+        result
+    }
+    pub fn pow(self, power: u64) -> Self {
+        let (mut base, mut power): (Self, u64) = (self, power);
+        let mut running: Self = Self::ONE;
+        while power > 0 {
+            if power & 1 == 1 { running *= base; }
+            base *= base;
+            power >>= 1;
+        }
+        running
+    }
+    fn build(v: i64, u: u128) -> Self {
+        let mut u: u128 = u % Self::modulus() as u128;
+        let mut v: i64 = v;
+        if u == 0 { return Self::ZERO };
+        while u.is_multiple_of(P as u128) { u /= P as u128; v += 1; }
+        Self { v, u: u as u64 }
+    }
+    fn written(&self, latex: bool) -> String {
+        let mut terms: Vec<String> = Vec::new();
+        for (indx, digit) in self.digits().into_iter().enumerate() {
+            if digit == 0 { continue };
+            let power: i64 = self.v + indx as i64;
+            terms.push(match (power, latex) {
+                (0, _) => digit.to_string(),
+                (1, false) => format!("{}*{}", digit, P),
+                (1, true) => format!("{} \\cdot {}", digit, P),
+                (_, false) => format!("{}*{}^{}", digit, P, power),
+                (_, true) => format!("{} \\cdot {}^{{{}}}", digit, P, power),
+            });
+        }
+        let horizon: i64 = if self.u == 0 { K as i64 } else { self.v + K as i64 };
+        terms.push(if latex { format!("O({}^{{{}}})", P, horizon) } else { format!("O({}^{})", P, horizon) });
+        terms.join(" + ")
+    }
+}
+
+fn split_power(n: i128, p: i128) -> (i64, i128) {
+    let (mut count, mut running): (i64, i128) = (0, n);
+    while running % p == 0 { running /= p; count += 1; }
+    (count, running)
+}
+
+fn unit_inverse(u: u64, modulus: u64) -> u64 {
+    let (mut r0, mut r1): (i128, i128) = (modulus as i128, u as i128);
+    let (mut t0, mut t1): (i128, i128) = (0, 1);
+    while r1 != 0 {
+        let q: i128 = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    t0.rem_euclid(modulus as i128) as u64
+}
+
+impl<const P: u64, const K: u32> Poly<PAdic<P, K>> {
+    pub fn hensel(&self, approx: PAdic<P, K>) -> Option<PAdic<P, K>> {
+        let slope: Self = self.dvt();
+        if slope.eval(approx).valuation() != Some(0) { return None };
+        if self.eval(approx).valuation().is_some_and(|v| v <= 0) { return None };
+        let mut root: PAdic<P, K> = approx;
+        for _ in 0..K {
+            let value: PAdic<P, K> = self.eval(root);
+            if value == PAdic::ZERO { break };
+            root -= value / slope.eval(root);
+        }
+        Some(root)
+    }
+    pub fn padic_roots(&self) -> Vec<PAdic<P, K>> {
+        let mut result: Vec<PAdic<P, K>> = Vec::new();
+        for residue in 0..P {
+            if let Some(root) = self.hensel(PAdic::new(0, residue)) { result.push(root); }
+        }
+        result
+    }
+}
+
+impl<const P: u64, const K: u32> Identity for PAdic<P, K> {
+    const ZERO: Self = Self { v: 0, u: 0 };
+    const ONE: Self = Self { v: 0, u: 1 };
+}
+impl<const P: u64, const K: u32> Inverse for PAdic<P, K> {
+    fn inv(self) -> Self {
+        if self.u == 0 { panic!("cannot invert the zero p-adic") };
+        Self { v: -self.v, u: unit_inverse(self.u, Self::modulus()) }
+    }
+}
+impl<const P: u64, const K: u32> Ring for PAdic<P, K> {}
+impl<const P: u64, const K: u32> Field for PAdic<P, K> {}
+
+impl<const P: u64, const K: u32> fmt::Display for PAdic<P, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.written(false))
+    }
+}
+impl<const P: u64, const K: u32> LaTeX for PAdic<P, K> {
+    fn latex(&self) -> String {
+        self.written(true)
+    }
+}
+
+impl<const P: u64, const K: u32> Neg for PAdic<P, K> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if self.u == 0 { return self };
+        Self { v: self.v, u: Self::modulus() - self.u }
+    }
+}
+impl<const P: u64, const K: u32> Add for PAdic<P, K> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        if self.u == 0 { return rhs };
+        if rhs.u == 0 { return self };
+        let (low, high): (Self, Self) = if self.v <= rhs.v { (self, rhs) } else { (rhs, self) };
+        let gap: i64 = high.v - low.v;
+        if gap >= K as i64 { return low };
+        let shifted: u128 = high.u as u128 * P.pow(gap as u32) as u128;
+        Self::build(low.v, low.u as u128 + shifted)
+    }
+}
+impl<const P: u64, const K: u32> Sub for PAdic<P, K> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+impl<const P: u64, const K: u32> Mul for PAdic<P, K> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.u == 0 || rhs.u == 0 { return Self::ZERO };
+        Self::build(self.v + rhs.v, self.u as u128 * rhs.u as u128)
+    }
+}
+impl<const P: u64, const K: u32> Div for PAdic<P, K> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inv())
+    }
+}
+impl<const P: u64, const K: u32> AddAssign for PAdic<P, K> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<const P: u64, const K: u32> SubAssign for PAdic<P, K> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<const P: u64, const K: u32> MulAssign for PAdic<P, K> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<const P: u64, const K: u32> DivAssign for PAdic<P, K> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,365 @@
+use std::ops::{
+    Neg, Add, Sub, Mul, Div, Rem,
+    AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use std::cmp::{PartialOrd, Ordering};
+use crate::rules::*;
+use crate::prim::*;
+use std::fmt;
+
+// An N-bit posit with ES exponent bits, 2 <= N <= 64.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Posit<const N: u32, const ES: u32> {
+    pub bits: u64,
+}
+
+#[allow(non_camel_case_types)]
+pub type p8 = Posit<8, 2>;
+#[allow(non_camel_case_types)]
+pub type p16 = Posit<16, 2>;
+#[allow(non_camel_case_types)]
+pub type p32 = Posit<32, 2>;
+
+impl<const N: u32, const ES: u32> Posit<N, ES> {
+    const MASK: u64 = if N == 64 { u64::MAX } else { (1 << N) - 1 };
+    pub const NAR: Self = Self { bits: 1 << (N - 1) };
+    pub const MAXPOS: Self = Self { bits: (1 << (N - 1)) - 1 };
+    pub const MINPOS: Self = Self { bits: 1 };
+
+    pub const fn raw(bits: u64) -> Self {
+        Self { bits: bits & Self::MASK }
+    }
+    pub const fn from_f64(x: f64) -> Self {
+        let bits: u64 = x.to_bits();
+        let neg: bool = bits >> 63 == 1;
+        let biased: i32 = ((bits >> 52) & 0x7ff) as i32;
+        let mantissa: u64 = bits & ((1 << 52) - 1);
+        if biased == 0x7ff { return Self::NAR };
+        if biased == 0 {
+            Self::encode(neg, -1074, mantissa as u128, false)
+        } else {
+            Self::encode(neg, biased - 1075, (mantissa | 1 << 52) as u128, false)
+        }
+    }
+    pub fn to_f64(self) -> f64 {
+        match Self::decode(self.bits) {
+            None => f64::NAN,
+            Some((neg, exp, sig)) => {
+                let magnitude: f64 = sig as f64 * 2f64.powi(exp);
+                if neg { -magnitude } else { magnitude }
+            },
+        }
+    }
+    pub fn is_nar(self) -> bool {
+        self == Self::NAR
+    }
+    pub fn trunc(self) -> Self {
+        match Self::decode(self.bits) {
+            None => Self::NAR,
+            Some((_, exp, _)) if exp >= 0 => self,
+            Some((neg, exp, sig)) => {
+                let shift: u32 = -exp as u32;
+                if shift >= 64 { return Self::ZERO };
+                Self::encode(neg, 0, (sig >> shift) as u128, false)
+            },
+        }
+    }
+    pub fn dot(left: &[Self], right: &[Self]) -> Self {
+        let mut quire: Quire<N, ES> = Quire::new();
+        for (a, b) in left.iter().zip(right) { quire.add_product(*a, *b); }
+        quire.to_posit()
+    }
+    const fn max_scale() -> i64 {
+        ((N - 2) as i64) << ES
+    }
+    // value = sig * 2^exp, with the hidden bit of sig at position 63
+    const fn decode(bits: u64) -> Option<(bool, i32, u64)> {
+        let bits: u64 = bits & Self::MASK;
+        if bits == 0 { return Some((false, 0, 0)) };
+        if bits == 1 << (N - 1) { return None };
+        let neg: bool = (bits >> (N - 1)) & 1 == 1;
+        let body: u64 = if neg { bits.wrapping_neg() & Self::MASK } else { bits };
+        let mut rest: u64 = body << (64 - N + 1);
+        let first: bool = rest >> 63 == 1;
+        let run: u32 = if first { rest.leading_ones() } else { rest.leading_zeros() };
+        let regime: i32 = if first { run as i32 - 1 } else { -(run as i32) };
+        rest = if run + 1 >= 64 { 0 } else { rest << (run + 1) };
+        let exponent: i32 = if ES == 0 { 0 } else { (rest >> (64 - ES)) as i32 };
+        rest = if ES == 0 { rest } else { rest << ES };
+        let sig: u64 = 1 << 63 | rest >> 1;
+        Some((neg, regime * (1 << ES) + exponent - 63, sig))
+    }
+    // rounds sig * 2^exp (plus a sticky remainder) to the nearest posit
+    const fn encode(neg: bool, exp: i32, sig: u128, sticky: bool) -> Self {
+        if sig == 0 { return if sticky { Self::MINPOS } else { Self::ZERO } };
+        let zeros: u32 = sig.leading_zeros();
+        let sig: u128 = sig << zeros;
+        let scale: i64 = exp as i64 + 127 - zeros as i64;
+        let magnitude: u64 = if scale > Self::max_scale() {
+            Self::MAXPOS.bits
+        } else if scale < -Self::max_scale() {
+            Self::MINPOS.bits
+        } else {
+            let regime: i64 = scale.div_euclid(1 << ES);
+            let exponent: u128 = scale.rem_euclid(1 << ES) as u128;
+            let (pattern, length): (u128, u32) = if regime >= 0 {
+                (((1 << (regime + 1)) - 1) << 1, regime as u32 + 2)
+            } else {
+                (1, (-regime) as u32 + 1)
+            };
+            let mut stream: u128 = pattern << (128 - length);
+            stream |= exponent << (128 - length - ES);
+            let fraction: u128 = sig << 1;
+            let offset: u32 = length + ES;
+            stream |= fraction >> offset;
+            let lost: bool = fraction << (128 - offset) != 0;
+            let keep: u32 = N - 1;
+            let mut result: u128 = stream >> (128 - keep);
+            let guard: bool = (stream >> (127 - keep)) & 1 == 1;
+            let rest: bool = stream << (keep + 1) != 0 || lost || sticky;
+            if guard && (rest || result & 1 == 1) { result += 1; }
+            if result > Self::MAXPOS.bits as u128 { result = Self::MAXPOS.bits as u128; }
+            if result == 0 { result = 1; }
+            result as u64
+        };
+        if neg { Self { bits: magnitude.wrapping_neg() & Self::MASK } } else { Self { bits: magnitude } }
+    }
+    fn signed(self) -> i64 {
+        (self.bits << (64 - N)) as i64
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Quire<const N: u32, const ES: u32> {
+    limbs: Vec<u64>,
+    nar: bool,
+}
+impl<const N: u32, const ES: u32> Default for Quire<N, ES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: u32, const ES: u32> Quire<N, ES> {
+    pub fn new() -> Self {
+        let width: i64 = 4 * Posit::<N, ES>::max_scale() + 256;
+        Self { limbs: vec![0; width as usize / 64 + 1], nar: false }
+    }
+    pub fn add_product(&mut self, left: Posit<N, ES>, right: Posit<N, ES>) {
+        match (Posit::<N, ES>::decode(left.bits), Posit::<N, ES>::decode(right.bits)) {
+            (Some((n1, e1, s1)), Some((n2, e2, s2))) => {
+                self.accumulate(n1 != n2, e1 + e2, s1 as u128 * s2 as u128);
+            },
+            _ => self.nar = true,
+        }
+    }
+    pub fn add(&mut self, value: Posit<N, ES>) {
+        self.add_product(value, Posit::ONE);
+    }
+    pub fn sub_product(&mut self, left: Posit<N, ES>, right: Posit<N, ES>) {
+        self.add_product(-left, right);
+    }
+    pub fn to_posit(&self) -> Posit<N, ES> {
+        if self.nar { return Posit::NAR };
+        let neg: bool = self.limbs[self.limbs.len()-1] >> 63 == 1;
+        let magnitude: Vec<u64> = if neg { negate(&self.limbs) } else { self.limbs.clone() };
+        let top: usize = match magnitude.iter().rposition(|limb| *limb != 0) {
+            None => return Posit::ZERO,
+            Some(indx) => indx * 64 + 63 - magnitude[indx].leading_zeros() as usize,
+        };
+        let low: usize = top.saturating_sub(127);
+        let mut sig: u128 = 0;
+        for bit in (low..=top).rev() {
+            sig = sig << 1 | ((magnitude[bit / 64] >> (bit % 64)) & 1) as u128;
+        }
+        let sticky: bool = (0..low).any(|bit| (magnitude[bit / 64] >> (bit % 64)) & 1 == 1);
+        Posit::encode(neg, low as i32 + Self::floor(), sig, sticky)
+    }
+    fn floor() -> i32 {
+        -2 * Posit::<N, ES>::max_scale() as i32 - 128
+    }
+    fn accumulate(&mut self, neg: bool, exp: i32, sig: u128) {
+        if sig == 0 { return };
+        let position: usize = (exp - Self::floor()) as usize;
+        let mut term: Vec<u64> = vec![0; self.limbs.len()];
+        let (limb, shift): (usize, u32) = (position / 64, (position % 64) as u32);
+        let wide: [u64; 3] = [
+            (sig << shift) as u64,
+            ((sig << shift) >> 64) as u64,
+            if shift == 0 { 0 } else { (sig >> (128 - shift)) as u64 },
+        ];
+        for (indx, part) in wide.iter().enumerate() {
+            if limb + indx < term.len() { term[limb + indx] = *part; }
+        }
+        if neg { term = negate(&term); }
+        let mut carry: bool = false;
+        for (total, part) in self.limbs.iter_mut().zip(term) {
+            let (sum, c1): (u64, bool) = total.overflowing_add(part);
+            let (sum, c2): (u64, bool) = sum.overflowing_add(carry as u64);
+            *total = sum;
+            carry = c1 || c2;
+        }
+    }
+}
+
+fn negate(limbs: &[u64]) -> Vec<u64> {
+    let mut result: Vec<u64> = Vec::new();
+    let mut carry: bool = true;
+    for limb in limbs {
+        let (sum, overflow): (u64, bool) = (!limb).overflowing_add(carry as u64);
+        result.push(sum);
+        carry = overflow;
+    }
+    result
+}
+
+impl<const N: u32, const ES: u32> Identity for Posit<N, ES> {
+    const ZERO: Self = Self { bits: 0 };
+    const ONE: Self = Self { bits: 1 << (N - 2) };
+}
+impl<const N: u32, const ES: u32> UsefulReals for Posit<N, ES> {
+    const TWO: Self = Self::from_f64(2.0);
+    const E: Self = Self::from_f64(std::f64::consts::E);
+    const TAU: Self = Self::from_f64(std::f64::consts::TAU);
+    const PI: Self = Self::from_f64(std::f64::consts::PI);
+    const HALFPI: Self = Self::from_f64(std::f64::consts::FRAC_PI_2);
+    const QTRPI: Self = Self::from_f64(std::f64::consts::FRAC_PI_4);
+}
+impl<const N: u32, const ES: u32> Inverse for Posit<N, ES> {
+    fn inv(self) -> Self { Self::ONE / self }
+}
+impl<const N: u32, const ES: u32> PowersOfTen for Posit<N, ES> {
+    fn order_of(power: isize) -> Self {
+        let mut running: Self = Self::ONE;
+        let ten: Self = Self::from_f64(10.0);
+        if power < 0 {
+            for _ in power..0 { running /= ten; }
+        } else {
+            for _ in 0..power { running *= ten; }
+        }
+        running
+    }
+}
+impl<const N: u32, const ES: u32> MagSquare for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> Ring for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> Field for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> RealArithmetic for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> PowersOfE for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> Magnitude for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> Reals for Posit<N, ES> {
+    const UNDEF: Self = Self::NAR;
+    const INFINITE: Self = Self::NAR;
+}
+impl<const N: u32, const ES: u32> Exponential for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> CircularTrig for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> HyperbolicTrig for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> CircularTrigInv for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> HyperbolicTrigInv for Posit<N, ES> {}
+impl<const N: u32, const ES: u32> Trigonometry for Posit<N, ES> {}
+
+impl<const N: u32, const ES: u32> PartialOrd for Posit<N, ES> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.signed().partial_cmp(&rhs.signed())
+    }
+}
+impl<const N: u32, const ES: u32> fmt::Display for Posit<N, ES> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_nar() { write!(f, "NaR") } else { write!(f, "{}", self.to_f64()) }
+    }
+}
+impl<const N: u32, const ES: u32> LaTeX for Posit<N, ES> {
+    fn latex(&self) -> String {
+        if self.is_nar() { String::from("\\text{NaR}") } else { format!("{}", self.to_f64()) }
+    }
+}
+
+impl<const N: u32, const ES: u32> Neg for Posit<N, ES> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { bits: self.bits.wrapping_neg() & Self::MASK }
+    }
+}
+impl<const N: u32, const ES: u32> Add for Posit<N, ES> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let ((n1, e1, s1), (n2, e2, s2)) = match (Self::decode(self.bits), Self::decode(rhs.bits)) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Self::NAR,
+        };
+        if s1 == 0 { return rhs };
+        if s2 == 0 { return self };
+        let ((nb, eb, sb), (ns, es, ss)) = if e1 >= e2 { ((n1, e1, s1), (n2, e2, s2)) } else { ((n2, e2, s2), (n1, e1, s1)) };
+        let big: u128 = (sb as u128) << 62;
+        let gap: u32 = (eb - es) as u32;
+        let (small, sticky): (u128, bool) = if gap <= 62 {
+            ((ss as u128) << (62 - gap), false)
+        } else if gap - 62 < 64 {
+            ((ss >> (gap - 62)) as u128, ss << (126 - gap) != 0)
+        } else {
+            (0, true)
+        };
+        if nb == ns { return Self::encode(nb, eb - 62, big + small, sticky) };
+        if big > small {
+            Self::encode(nb, eb - 62, big - small - sticky as u128, sticky)
+        } else {
+            Self::encode(ns, eb - 62, small - big, false)
+        }
+    }
+}
+impl<const N: u32, const ES: u32> Sub for Posit<N, ES> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+impl<const N: u32, const ES: u32> Mul for Posit<N, ES> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        match (Self::decode(self.bits), Self::decode(rhs.bits)) {
+            (Some((n1, e1, s1)), Some((n2, e2, s2))) => Self::encode(n1 != n2, e1 + e2, s1 as u128 * s2 as u128, false),
+            _ => Self::NAR,
+        }
+    }
+}
+impl<const N: u32, const ES: u32> Div for Posit<N, ES> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        match (Self::decode(self.bits), Self::decode(rhs.bits)) {
+            (_, Some((_, _, 0))) => Self::NAR,
+            (Some((n1, e1, s1)), Some((n2, e2, s2))) => {
+                let top: u128 = (s1 as u128) << 64;
+                Self::encode(n1 != n2, e1 - e2 - 64, top / s2 as u128, !top.is_multiple_of(s2 as u128))
+            },
+            _ => Self::NAR,
+        }
+    }
+}
+impl<const N: u32, const ES: u32> Rem for Posit<N, ES> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self - (self / rhs).trunc() * rhs
+    }
+}
+impl<const N: u32, const ES: u32> AddAssign for Posit<N, ES> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<const N: u32, const ES: u32> SubAssign for Posit<N, ES> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<const N: u32, const ES: u32> MulAssign for Posit<N, ES> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<const N: u32, const ES: u32> DivAssign for Posit<N, ES> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+impl<const N: u32, const ES: u32> RemAssign for Posit<N, ES> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
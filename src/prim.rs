@@ -1,25 +1,27 @@
 use crate::rules::*;
 use crate::cc::Comp;
+use crate::rat::Rat;
 
 fn exp_raw<X: Reals>(inp: X, iterations: usize) -> X {
+    let inp: X = inp.settle();
     let mut total: X = X::ZERO;
     let mut running: X = X::ONE;
     let mut indx: X = X::ONE;
     for _ in 0..iterations {
-        total += running;
-        running *= inp / indx;
+        total = (total + running).settle();
+        running = (running * (inp / indx)).settle();
         indx += X::ONE;
     }
     total
 }
 fn ln_raw<X: Reals>(inp: X, iterations: usize) -> X {
-    let centered: X = inp - X::ONE;
+    let centered: X = (inp - X::ONE).settle();
     let mut total: X = X::ZERO;
     let mut running: X = centered;
     let mut indx: X = X::ONE;
     for _ in 0..iterations {
-        total += running / indx;
-        running *= -centered;
+        total = (total + running / indx).settle();
+        running = (running * -centered).settle();
         indx += X::ONE;
     }
     total
@@ -48,7 +50,7 @@ fn ln_mag_fix<X: Reals>(mag: X) -> (X, X, bool) {
     let mut extra: X = X::ZERO;
     let mut neg: bool = false;
     if out.mag2() > X::ONE { out = out.inv(); neg = true; }
-    while out < X::ONE - X::E.inv() { out *= X::E; extra += X::ONE; }
+    while out < X::ONE - X::E.inv() { out = (out * X::E).settle(); extra += X::ONE; }
     (out, extra, neg)
 }
 fn ln_angle_fix<X: Reals>(unit: Comp<X>) -> (Comp<X>, X) {
@@ -66,7 +68,7 @@ fn ln_angle_fix<X: Reals>(unit: Comp<X>) -> (Comp<X>, X) {
 pub trait Exponential: Reals {
     fn exp(self, iterations: usize) -> Self {
         let (fixed, extra, neg): (Self, isize, bool) = exp_real_fix(self);
-        let out: Self = exp_raw(fixed, iterations) * Self::etothe(extra);
+        let out: Self = (exp_raw(fixed, iterations) * Self::etothe(extra)).settle();
         if neg { out.inv() } else { out }
     }
     fn lnn(self, iterations: usize) -> Self {
@@ -81,6 +83,7 @@ pub trait Exponential: Reals {
 }
 impl Exponential for f32 {}
 impl Exponential for f64 {}
+impl<R: RoundingPolicy> Exponential for Rat<R> {}
 impl<R: Reals> Exponential for Comp<R> {
     fn exp(self, iterations: usize) -> Self {
         let (r_fixed, extra, neg): (R, isize, bool) = exp_real_fix(self.r);
@@ -91,8 +94,8 @@ impl<R: Reals> Exponential for Comp<R> {
         out
     }
     fn lnn(self, iterations: usize) -> Self {
-        let mag: Self = self.mag1(Self::order_of(-6) * self.mag2());
-        let unit: Self = self / mag;
+        let mag: Self = self.mag1(Self::order_of(-6) * self.mag2().settle());
+        let unit: Self = (self / mag).settle();
         let mag: R = mag.r;
         let (mag_fix, extra_real, invert): (R, R, bool) = ln_mag_fix(mag);
         let mag_fix = Self::nre(mag_fix);
@@ -103,23 +106,25 @@ impl<R: Reals> Exponential for Comp<R> {
 }
 
 fn sin_raw<X: Reals>(inp: X, iterations: usize) -> X {
+    let inp: X = inp.settle();
     let mut total: X = X::ZERO;
     let mut running: X = inp;
     let mut indx: X = X::TWO;
     for _ in 0..iterations {
-        total += running;
-        running *= -inp * inp / indx / (indx + X::ONE);
+        total = (total + running).settle();
+        running = (running * -inp * inp / indx / (indx + X::ONE)).settle();
         indx += X::TWO;
     }
     total
 }
 fn cos_raw<X: Reals>(inp: X, iterations: usize) -> X {
+    let inp: X = inp.settle();
     let mut total: X = X::ZERO;
     let mut running: X = X::ONE;
     let mut indx: X = X::ONE;
     for _ in 0..iterations {
-        total += running;
-        running *= -inp * inp / indx / (indx + X::ONE);
+        total = (total + running).settle();
+        running = (running * -inp * inp / indx / (indx + X::ONE)).settle();
         indx += X::TWO;
     }
     total
@@ -203,12 +208,16 @@ pub trait HyperbolicTrigInv: Reals + Exponential {
 
 impl CircularTrig for f32 {}
 impl CircularTrig for f64 {}
+impl<R: RoundingPolicy> CircularTrig for Rat<R> {}
 impl HyperbolicTrig for f32 {}
 impl HyperbolicTrig for f64 {}
+impl<R: RoundingPolicy> HyperbolicTrig for Rat<R> {}
 impl CircularTrigInv for f32 {}
 impl CircularTrigInv for f64 {}
+impl<R: RoundingPolicy> CircularTrigInv for Rat<R> {}
 impl HyperbolicTrigInv for f32 {}
 impl HyperbolicTrigInv for f64 {}
+impl<R: RoundingPolicy> HyperbolicTrigInv for Rat<R> {}
 
 impl<R: RealArithmetic> Comp<R> {
     pub fn ccw(self) -> Self {
@@ -265,4 +274,5 @@ pub trait Trigonometry:
 
 impl Trigonometry for f32 {}
 impl Trigonometry for f64 {}
+impl<R: RoundingPolicy> Trigonometry for Rat<R> {}
 impl<R: Reals> Trigonometry for Comp<R> {}
\ No newline at end of file
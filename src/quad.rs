@@ -0,0 +1,160 @@
+use std::ops::{
+    Neg, Add, Sub, Mul, Div,
+    AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::rules::*;
+use crate::alg::Poly;
+use crate::rat::Rat;
+use crate::nt::{exact_root, mobius};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy)]
+pub struct QuadField<R: RealArithmetic + TryFrom<i64>, const D: i64> {
+    pub a: Rat<R>,
+    pub b: Rat<R>,
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> QuadField<R, D> {
+    pub fn new(a: Rat<R>, b: Rat<R>) -> Self {
+        Self { a, b }
+    }
+    pub fn whole(a: Rat<R>) -> Self {
+        Self { a, b: Rat::ZERO }
+    }
+    pub fn root() -> Self {
+        Self { a: Rat::ZERO, b: Rat::ONE }
+    }
+    pub fn conj(self) -> Self {
+        Self { a: self.a, b: -self.b }
+    }
+    pub fn norm(self) -> Rat<R> {
+        self.a * self.a - Self::dee() * self.b * self.b
+    }
+    pub fn trace(self) -> Rat<R> {
+        self.a + self.a
+    }
+    fn dee() -> Rat<R> {
+        match R::try_from(D) {
+            Ok(d) => Rat::whole(d),
+            Err(_) => panic!("{} does not fit in the coefficient type", D),
+        }
+    }
+}
+
+impl<R: RealArithmetic + TryFrom<i64>> Poly<Rat<R>> {
+    pub fn discriminant(&self) -> Rat<R> {
+        let poly: Self = self.clone().trim();
+        if poly.le != 3 { panic!("discriminant is only defined here for quadratics") };
+        let four: Rat<R> = Rat::whole(R::ONE + R::ONE + R::ONE + R::ONE);
+        poly.co[1] * poly.co[1] - four * poly.co[2] * poly.co[0]
+    }
+}
+impl<R: Integer + TryFrom<i64>> Poly<Rat<R>> {
+    // both roots in Q(√D), rational ones included, or None if they lie outside it or overflow
+    pub fn quadratic_roots<const D: i64>(&self) -> Option<(QuadField<R, D>, QuadField<R, D>)> {
+        if D == 0 || D == 1 || mobius(D) == 0 { panic!("D must be squarefree and not a perfect square") };
+        let poly: Self = self.clone().trim();
+        if poly.le != 3 { return None };
+        let twice: Rat<R> = poly.co[2] + poly.co[2];
+        let real: Rat<R> = -poly.co[1] / twice;
+        let discriminant: Rat<R> = poly.discriminant();
+        if let (Some(top), Some(bottom)) = (exact_root(discriminant.n, 2), exact_root(discriminant.d, 2)) {
+            let offset: Rat<R> = Rat::new(top, bottom) / twice;
+            return Some((QuadField::whole(real + offset), QuadField::whole(real - offset)));
+        }
+        let dee: R = QuadField::<R, D>::dee().n;
+        let scaled: Rat<R> = Rat::new(discriminant.n.checked_mul(dee)?, discriminant.d);
+        let (top, bottom): (R, R) = (exact_root(scaled.n, 2)?, exact_root(scaled.d, 2)?);
+        let surd: Rat<R> = Rat::new(top, bottom) / QuadField::<R, D>::dee() / twice;
+        Some((QuadField::new(real, surd), QuadField::new(real, -surd)))
+    }
+}
+
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> PartialEq for QuadField<R, D> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.a == rhs.a && self.b == rhs.b
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Identity for QuadField<R, D> {
+    const ZERO: Self = Self { a: Rat::ZERO, b: Rat::ZERO };
+    const ONE: Self = Self { a: Rat::ONE, b: Rat::ZERO };
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Inverse for QuadField<R, D> {
+    fn inv(self) -> Self {
+        let divisor: Rat<R> = self.norm();
+        Self { a: self.a / divisor, b: -self.b / divisor }
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Ring for QuadField<R, D> {}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Field for QuadField<R, D> {}
+
+impl<R: RealArithmetic + TryFrom<i64> + fmt::Display, const D: i64> fmt::Display for QuadField<R, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.b < Rat::ZERO {
+            write!(f, "{}-{}√{}", self.a, -self.b, D)
+        } else {
+            write!(f, "{}+{}√{}", self.a, self.b, D)
+        }
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64> + LaTeX, const D: i64> LaTeX for QuadField<R, D> {
+    fn latex(&self) -> String {
+        if self.b < Rat::ZERO {
+            format!("{} - {}\\sqrt{{{}}}", self.a.latex(), (-self.b).latex(), D)
+        } else {
+            format!("{} + {}\\sqrt{{{}}}", self.a.latex(), self.b.latex(), D)
+        }
+    }
+}
+
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Neg for QuadField<R, D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { a: -self.a, b: -self.b }
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Add for QuadField<R, D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self { a: self.a + rhs.a, b: self.b + rhs.b }
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Sub for QuadField<R, D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self { a: self.a - rhs.a, b: self.b - rhs.b }
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Mul for QuadField<R, D> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            a: self.a * rhs.a + Self::dee() * self.b * rhs.b,
+            b: self.a * rhs.b + self.b * rhs.a,
+        }
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> Div for QuadField<R, D> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inv())
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> AddAssign for QuadField<R, D> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> SubAssign for QuadField<R, D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> MulAssign for QuadField<R, D> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<R: RealArithmetic + TryFrom<i64>, const D: i64> DivAssign for QuadField<R, D> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
@@ -1,8 +1,9 @@
 use std::ops::{
     Neg, Add, Sub, Mul, Div, Rem,
     AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
-use std::cmp::{PartialEq, PartialOrd};
+use std::cmp::{PartialEq, PartialOrd, Ordering};
 use crate::rules::*;
+use crate::nt::{gcd, exact_root};
 use std::fmt;
 
 #[derive(Debug, Clone, Copy)]
@@ -15,11 +16,15 @@ impl<R: RealArithmetic> Rat<R> {
         Self { n, d }
     }
     pub fn new(n: R, d: R) -> Self {
+        if d == R::ZERO {
+            let sign: R = if n > R::ZERO { R::ONE } else if n < R::ZERO { -R::ONE } else { R::ZERO };
+            return Self { n: sign, d };
+        }
         let (mut n, mut d): (R, R) = (n, d);
         let mut positive: bool = true;
         if n < R::ZERO { positive = !positive; n = -n; }
         if d < R::ZERO { positive = !positive; d = -d; }
-        let factor: R = gcf(n, d);
+        let factor: R = gcd(n, d);
         n /= factor;
         d /= factor;
         if !positive { n = -n; }
@@ -28,29 +33,174 @@ impl<R: RealArithmetic> Rat<R> {
     pub fn whole(n: R) -> Self {
         Self { n, d: R::ONE }
     }
+    // the closest fraction whose numerator and denominator are at most bound,
+    // or the closest whole number when the value itself is out of bounds
+    pub fn bounded(self, bound: R) -> Self {
+        let reduced: Self = Self::new(self.n, self.d);
+        if reduced.d == R::ZERO { return reduced };
+        let negative: bool = reduced.n < R::ZERO;
+        let magnitude: Self = if negative { -reduced } else { reduced };
+        if magnitude.n <= bound && magnitude.d <= bound { return reduced };
+        let result: Self = if magnitude > Self::whole(bound) {
+            magnitude.limit(R::ONE)
+        } else if magnitude > Self::ONE {
+            let flipped: Self = magnitude.inv().limit(bound);
+            Self { n: flipped.d, d: flipped.n }
+        } else {
+            magnitude.limit(bound)
+        };
+        if negative { -result } else { result }
+    }
+}
+
+impl<R: Integer> Rat<R> {
+    // the exact k-th root, when both numerator and denominator have one
+    pub fn exact_root(self, k: u32) -> Option<Self> {
+        let reduced: Self = Self::new(self.n, self.d);
+        if reduced.d == R::ZERO { return None };
+        Some(Self::raw(exact_root(reduced.n, k)?, exact_root(reduced.d, k)?))
+    }
 }
 
-fn gcf<R: RealArithmetic>(inp1: R, inp2: R) -> R {
-    let (mut n1, mut n2): (R, R) = (inp1, inp2);
-    if n1 < R::ZERO || n2 < R::ZERO { panic!("cannot GCF negative numbers") };
+impl<R: CheckedArithmetic> Rat<R> {
+    // like new, but None when the normalized sign cannot be represented, as with MIN/-1
+    pub fn checked_new(n: R, d: R) -> Option<Self> {
+        if d == R::ZERO { return Some(Self::new(n, d)) };
+        let factor: R = checked_gcd(n, d);
+        let (mut n, mut d): (R, R) = (n / factor, d / factor);
+        if d < R::ZERO { n = n.checked_neg()?; d = d.checked_neg()?; }
+        Some(Self { n, d })
+    }
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self { n: self.n.checked_neg()?, d: self.d })
+    }
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (left, right): (R, R) = checked_cancel(rhs.d, self.d);
+        let n: R = self.n.checked_mul(left)?.checked_add(rhs.n.checked_mul(right)?)?;
+        let d: R = self.d.checked_mul(left)?;
+        Self::checked_new(n, d)
+    }
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (n1, d2): (R, R) = checked_cancel(self.n, rhs.d);
+        let (n2, d1): (R, R) = checked_cancel(rhs.n, self.d);
+        Self::checked_new(n1.checked_mul(n2)?, d1.checked_mul(d2)?)
+    }
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.n == R::ZERO { return None };
+        self.checked_mul(Self { n: rhs.d, d: rhs.n })
+    }
+    // the floor, then distinct unit fractions for what is left in [0, 1), or None once the
+    // denominators, which can grow doubly exponentially, overflow
+    pub fn greedy_egyptian(&self) -> Option<(R, Vec<R>)> {
+        let reduced: Self = Self::checked_new(self.n, self.d)?;
+        let whole: R = reduced.floor();
+        let mut rest: Self = reduced.checked_sub(Self::whole(whole))?;
+        let mut result: Vec<R> = Vec::new();
+        while rest != Self::ZERO {
+            let next: R = rest.inv().ceil();
+            result.push(next);
+            rest = rest.checked_sub(Self { n: R::ONE, d: next })?;
+        }
+        Some((whole, result))
+    }
+}
+impl<R: Promote> Rat<R> {
+    pub fn promote(self) -> Rat<R::Wide> {
+        Rat { n: self.n.promote(), d: self.d.promote() }
+    }
+    pub fn demote(wide: Rat<R::Wide>) -> Option<Self> {
+        Some(Self { n: R::demote(wide.n)?, d: R::demote(wide.d)? })
+    }
+    pub fn nearest(wide: Rat<R::Wide>) -> Self {
+        let wide: Rat<R::Wide> = Rat::new(wide.n, wide.d);
+        if let Some(exact) = Self::demote(wide) { return exact };
+        let negative: bool = wide.n < R::Wide::ZERO;
+        let magnitude: Rat<R::Wide> = if negative { -wide } else { wide };
+        let result: Self = Self::demote(magnitude.bounded(R::MAX.promote())).unwrap_or(Self::whole(R::MAX));
+        if negative { -result } else { result }
+    }
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::nearest(self.promote() + rhs.promote())
+    }
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::nearest(self.promote() - rhs.promote())
+    }
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self::nearest(self.promote() * rhs.promote())
+    }
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        Self::nearest(self.promote() / rhs.promote())
+    }
+}
+
+fn cancel<R: RealArithmetic>(n: R, d: R) -> (R, R) {
+    let factor: R = gcd(n, d);
+    if factor == R::ZERO { (n, d) } else { (n / factor, d / factor) }
+}
+// a gcd that is positive unless it is MIN itself, so dividing by it never overflows
+fn checked_gcd<R: CheckedArithmetic>(a: R, b: R) -> R {
+    let (mut a, mut b): (R, R) = (a, b);
+    while b != R::ZERO {
+        // MIN % -1 overflows, while the remainder by 1 is the same zero
+        if b == -R::ONE { b = R::ONE; }
+        (a, b) = (b, a % b);
+    }
+    if a < R::ZERO { a.checked_neg().unwrap_or(a) } else { a }
+}
+fn checked_cancel<R: CheckedArithmetic>(n: R, d: R) -> (R, R) {
+    let factor: R = checked_gcd(n, d);
+    if factor == R::ZERO { (n, d) } else { (n / factor, d / factor) }
+}
+// compares n1/d1 with n2/d2 through their continued fraction terms,
+// so no products of numerators and denominators are ever formed
+fn compare<R: RealArithmetic>(n1: R, d1: R, n2: R, d2: R) -> Option<Ordering> {
+    if d1 == R::ZERO || d2 == R::ZERO { return compare_infinite(n1, d1, n2, d2) };
+    let (mut n1, mut d1, mut n2, mut d2): (R, R, R, R) = (n1, d1, n2, d2);
+    if d1 < R::ZERO { n1 = -n1; d1 = -d1; }
+    if d2 < R::ZERO { n2 = -n2; d2 = -d2; }
+    let mut flipped: bool = false;
     loop {
-        if n1 == R::ZERO { return n2 };
-        if n2 == R::ZERO { return n1 };
-        if n1 > n2 { n1 %= n2; }
-        else if n2 > n1 { n2 %= n1; }
-        else { return n1 };
+        let (mut q1, mut q2): (R, R) = (n1 / d1, n2 / d2);
+        let (mut r1, mut r2): (R, R) = (n1 - q1 * d1, n2 - q2 * d2);
+        if r1 < R::ZERO { q1 -= R::ONE; r1 += d1; }
+        if r2 < R::ZERO { q2 -= R::ONE; r2 += d2; }
+        let order: Option<Ordering> = match q1.partial_cmp(&q2)? {
+            Ordering::Equal if r1 == R::ZERO && r2 == R::ZERO => Some(Ordering::Equal),
+            Ordering::Equal if r1 == R::ZERO => Some(Ordering::Less),
+            Ordering::Equal if r2 == R::ZERO => Some(Ordering::Greater),
+            Ordering::Equal => None,
+            unequal => Some(unequal),
+        };
+        if let Some(order) = order {
+            return Some(if flipped { order.reverse() } else { order });
+        }
+        (n1, d1, n2, d2) = (d1, r1, d2, r2);
+        flipped = !flipped;
     }
 }
 
+// 0/0 is unordered, while ±1/0 sit beyond every finite value
+fn compare_infinite<R: RealArithmetic>(n1: R, d1: R, n2: R, d2: R) -> Option<Ordering> {
+    let rank = |n: R, d: R| -> Option<i8> {
+        if d != R::ZERO { Some(0) }
+        else if n > R::ZERO { Some(1) }
+        else if n < R::ZERO { Some(-1) }
+        else { None }
+    };
+    rank(n1, d1)?.partial_cmp(&rank(n2, d2)?)
+}
+
 impl<R: RealArithmetic> PartialEq for Rat<R> {
     fn eq(&self, rhs: &Self) -> bool {
-        self.n * rhs.d == self.d * rhs.n
+        compare(self.n, self.d, rhs.n, rhs.d) == Some(Ordering::Equal)
     }
 }
 impl<R: RealArithmetic> PartialOrd for Rat<R> {
-    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
-        let quantity: R = self.n * rhs.d - self.d * rhs.n;
-        quantity.partial_cmp(&R::ZERO)
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        compare(self.n, self.d, rhs.n, rhs.d)
     }
 }
 impl<R: RealArithmetic> Identity for Rat<R> {
@@ -71,7 +221,37 @@ impl<R: RealArithmetic + PowersOfTen> PowersOfTen for Rat<R> {
         }
     }
 }
-impl<R: RealArithmetic> MagSquare for Rat<R> {} 
+impl<R: RoundingPolicy> UsefulReals for Rat<R> {
+    const TWO: Self = Self { n: R::TWO.0, d: R::TWO.1 };
+    const E: Self = Self { n: R::E.0, d: R::E.1 };
+    const TAU: Self = Self { n: R::TAU.0, d: R::TAU.1 };
+    const PI: Self = Self { n: R::PI.0, d: R::PI.1 };
+    const HALFPI: Self = Self { n: R::HALFPI.0, d: R::HALFPI.1 };
+    const QTRPI: Self = Self { n: R::QTRPI.0, d: R::QTRPI.1 };
+    fn settle(self) -> Self { self.bounded(R::BOUND) }
+}
+impl<R: RealArithmetic> MagSquare for Rat<R> {}
+impl<R: RoundingPolicy> Magnitude for Rat<R> {
+    fn rrt(self, error: Self) -> Self {
+        if let Some(root) = self.exact_root(2) { return root };
+        let (mut t1, mut t2): (Self, Self) = (Self::SEED, Self::SEED + Self::ONE);
+        let target: Self = self.settle();
+        let mut counter: usize = 0;
+        while (t2 - t1).mag2() > error {
+            if counter > 20 { break }; counter += 1;
+            t1 = t2;
+            t2 = (t2 - (t2*t2 - target) / (Self::TWO * t2)).settle();
+        }
+        t2
+    }
+}
+impl<R: RoundingPolicy> PowersOfE for Rat<R> {}
+impl<R: RoundingPolicy> Reals for Rat<R> {
+    const UNDEF: Self = Self { n: R::ZERO, d: R::ZERO };
+    const INFINITE: Self = Self { n: R::ONE, d: R::ZERO };
+}
+impl<R: RealArithmetic> Ring for Rat<R> {}
+impl<R: RealArithmetic> Field for Rat<R> {}
 impl<R: RealArithmetic> RealArithmetic for Rat<R> {}
 impl<R: RealArithmetic + fmt::Display> fmt::Display for Rat<R> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -84,6 +264,207 @@ impl<R: RealArithmetic + LaTeX> LaTeX for Rat<R> {
     }
 }
 
+impl<R: RealArithmetic> Rat<R> {
+    // the rounding functions panic on ±1/0 and 0/0, which have no integer part
+    pub fn trunc(&self) -> R {
+        if self.d == R::ZERO { panic!("rounding of an infinite or undefined Rat") };
+        self.n / self.d
+    }
+    pub fn floor(&self) -> R {
+        let whole: R = self.trunc();
+        if Self::whole(whole) > *self { whole - R::ONE } else { whole }
+    }
+    pub fn ceil(&self) -> R {
+        let whole: R = self.trunc();
+        if Self::whole(whole) < *self { whole + R::ONE } else { whole }
+    }
+    // halves round away from zero, like f64::round
+    pub fn round(&self) -> R {
+        if self.d == R::ZERO { panic!("rounding of an infinite or undefined Rat") };
+        let reduced: Self = Self::new(self.n, self.d);
+        let whole: R = reduced.trunc();
+        let mut rest: R = reduced.n % reduced.d;
+        if rest < R::ZERO { rest = -rest; }
+        if rest + rest < reduced.d { whole }
+        else if reduced.n < R::ZERO { whole - R::ONE }
+        else { whole + R::ONE }
+    }
+    pub fn mixed(&self) -> (R, Self) {
+        let reduced: Self = Self::new(self.n, self.d);
+        let whole: R = reduced.trunc();
+        (whole, Self { n: reduced.n % reduced.d, d: reduced.d })
+    }
+    // fewest terms, and among those the smallest largest denominator
+    pub fn shortest_egyptian(&self) -> (R, Vec<R>) {
+        let (whole, target): (R, Self) = self.proper();
+        if target == Self::ZERO { return (whole, Vec::new()) };
+        let mut length: usize = 1;
+        loop {
+            let mut best: Option<Vec<R>> = None;
+            egyptian_search(target, R::ONE, length, &mut Vec::new(), &mut best);
+            if let Some(found) = best { return (whole, found) };
+            length += 1;
+        }
+    }
+    fn proper(&self) -> (R, Self) {
+        let reduced: Self = Self::new(self.n, self.d);
+        let whole: R = reduced.floor();
+        (whole, reduced - Self::whole(whole))
+    }
+}
+
+fn egyptian_search<R: RealArithmetic>(rest: Rat<R>, above: R, left: usize, chosen: &mut Vec<R>, best: &mut Option<Vec<R>>) {
+    if left == 1 {
+        if rest.n != R::ONE || rest.d <= above { return };
+        if best.as_ref().is_some_and(|found| found[found.len()-1] <= rest.d) { return };
+        chosen.push(rest.d);
+        *best = Some(chosen.clone());
+        chosen.pop();
+        return;
+    }
+    let mut count: R = R::ZERO;
+    for _ in 0..left { count += R::ONE; }
+    let (mut next, last): (R, R) = (rest.inv().ceil(), (Rat::whole(count) / rest).floor());
+    if next <= above { next = above + R::ONE; }
+    while next <= last {
+        if best.as_ref().is_some_and(|found| found[found.len()-1] <= next) { return };
+        chosen.push(next);
+        egyptian_search(rest - Rat { n: R::ONE, d: next }, next, left - 1, chosen, best);
+        chosen.pop();
+        next += R::ONE;
+    }
+}
+
+impl<R: RealArithmetic + fmt::Display> Rat<R> {
+    pub fn mixed_string(&self) -> String {
+        self.written_mixed(false)
+    }
+    pub fn latex_mixed(&self) -> String {
+        self.written_mixed(true)
+    }
+    pub fn latex_floor(&self) -> String {
+        format!("\\left\\lfloor \\frac{{{}}}{{{}}} \\right\\rfloor = {}", self.n, self.d, self.floor())
+    }
+    pub fn latex_ceil(&self) -> String {
+        format!("\\left\\lceil \\frac{{{}}}{{{}}} \\right\\rceil = {}", self.n, self.d, self.ceil())
+    }
+    pub fn latex_round(&self) -> String {
+        format!("\\left\\lfloor \\frac{{{}}}{{{}}} \\right\\rceil = {}", self.n, self.d, self.round())
+    }
+    pub fn latex_trunc(&self) -> String {
+        format!("\\operatorname{{trunc}}\\left(\\frac{{{}}}{{{}}}\\right) = {}", self.n, self.d, self.trunc())
+    }
+    fn written_mixed(&self, latex: bool) -> String {
+        let (whole, part): (R, Self) = self.mixed();
+        let sign: &str = if part.n < R::ZERO && whole == R::ZERO { "-" } else { "" };
+        let part_n: R = if part.n < R::ZERO { -part.n } else { part.n };
+        let fraction: String = if latex {
+            format!("\\frac{{{}}}{{{}}}", part_n, part.d)
+        } else {
+            format!("{}/{}", part_n, part.d)
+        };
+        if part.n == R::ZERO { format!("{}", whole) }
+        else if whole == R::ZERO { format!("{}{}", sign, fraction) }
+        else if latex { format!("{}{}", whole, fraction) }
+        else { format!("{} {}", whole, fraction) }
+    }
+}
+impl<R: CheckedArithmetic + fmt::Display> Rat<R> {
+    pub fn latex_egyptian(&self, shortest: bool) -> Option<String> {
+        let (whole, terms): (R, Vec<R>) = if shortest { self.shortest_egyptian() } else { self.greedy_egyptian()? };
+        let mut written: Vec<String> = terms.iter().map(|term| format!("\\frac{{1}}{{{}}}", term)).collect();
+        if whole != R::ZERO || written.is_empty() { written.insert(0, format!("{}", whole)); }
+        Some(written.join(" + "))
+    }
+}
+
+impl<R: Integer> Rat<R> {
+    // the repeating part is written in parentheses; its length can reach d - 1 digits,
+    // so this takes time and memory proportional to the denominator in the worst case
+    pub fn decimal(&self) -> String {
+        if self.d == R::ZERO {
+            return String::from(if self.n > R::ZERO { "∞" } else if self.n < R::ZERO { "-∞" } else { "NaN" });
+        }
+        let reduced: Self = Self::new(self.n, self.d);
+        let (n, d): (i128, u128) = (reduced.n.to_i128(), reduced.d.to_i128() as u128);
+        let mut result: String = String::new();
+        if n < 0 { result.push('-'); }
+        result += &format!("{}", n.unsigned_abs() / d);
+        let mut remainder: u128 = n.unsigned_abs() % d;
+        if remainder == 0 { return result };
+        // the next digit and remainder of 10 r / d, by repeated addition since 10 r can overflow
+        let step = |r: u128| -> (u128, u128) {
+            let (mut digit, mut next): (u128, u128) = (0, 0);
+            for _ in 0..10 {
+                next += r;
+                if next >= d { next -= d; digit += 1; }
+            }
+            (digit, next)
+        };
+        // Floyd's cycle finding on the remainders, where a terminating expansion loops at zero
+        let (mut slow, mut fast): (u128, u128) = (step(remainder).1, step(step(remainder).1).1);
+        while slow != fast { slow = step(slow).1; fast = step(step(fast).1).1; }
+        let mut start: usize = 0;
+        slow = remainder;
+        while slow != fast { slow = step(slow).1; fast = step(fast).1; start += 1; }
+        let mut period: usize = 1;
+        fast = step(slow).1;
+        while slow != fast { fast = step(fast).1; period += 1; }
+        let mut digits: String = String::new();
+        for _ in 0..start + period {
+            let (digit, next): (u128, u128) = step(remainder);
+            digits += &format!("{}", digit);
+            remainder = next;
+        }
+        if slow == 0 { return format!("{}.{}", result, &digits[..start]) };
+        format!("{}.{}({})", result, &digits[..start], &digits[start..])
+    }
+}
+impl<R: CheckedArithmetic + PowersOfTen + std::str::FromStr> std::str::FromStr for Rat<R> {
+    type Err = ();
+    fn from_str(slice: &str) -> Result<Self, Self::Err> {
+        if let Some(v) = slice.find('/') {
+            let (top, bottom): (Self, Self) = (parse_decimal(&slice[..v])?, parse_decimal(&slice[v+1..])?);
+            if bottom.n == R::ZERO { return Ok(Self::new(top.n, R::ZERO)) };
+            return top.checked_div(bottom).ok_or(());
+        }
+        parse_decimal(slice)
+    }
+}
+// reads [-]digits[.digits[(digits)]], where the bracketed block repeats forever,
+// failing when any intermediate value overflows
+fn parse_decimal<R: CheckedArithmetic + PowersOfTen + std::str::FromStr>(slice: &str) -> Result<Rat<R>, ()> {
+    let slice: &str = slice.trim();
+    let (negative, slice): (bool, &str) = match slice.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, slice.strip_prefix('+').unwrap_or(slice)),
+    };
+    let (whole, rest): (&str, &str) = slice.split_once('.').unwrap_or((slice, ""));
+    let (fixed, repeating): (&str, &str) = match rest.split_once('(') {
+        Some((fixed, repeating)) => (fixed, repeating.strip_suffix(')').ok_or(())?),
+        None => (rest, ""),
+    };
+    let digits = |part: &str| -> Result<R, ()> {
+        if !part.bytes().all(|byte| byte.is_ascii_digit()) { return Err(()) };
+        if part.is_empty() { Ok(R::ZERO) } else { part.parse::<R>().map_err(|_| ()) }
+    };
+    let power = |length: usize| -> Result<R, ()> {
+        let mut result: R = R::ONE;
+        for _ in 0..length { result = result.checked_mul(R::order_of(1)).ok_or(())?; }
+        Ok(result)
+    };
+    if whole.is_empty() && fixed.is_empty() && repeating.is_empty() { return Err(()) };
+    if slice.contains('(') && repeating.is_empty() { return Err(()) };
+    let shift: R = power(fixed.len())?;
+    let mut result: Rat<R> = Rat::whole(digits(whole)?).checked_add(Rat::new(digits(fixed)?, shift)).ok_or(())?;
+    if !repeating.is_empty() {
+        let period: R = power(repeating.len())? - R::ONE;
+        let below: R = shift.checked_mul(period).ok_or(())?;
+        result = result.checked_add(Rat::new(digits(repeating)?, below)).ok_or(())?;
+    }
+    Ok(if negative { -result } else { result })
+}
+
 impl<R: RealArithmetic> Neg for Rat<R> {
     type Output = Self;
     fn neg(self) -> Self {
@@ -93,31 +474,37 @@ impl<R: RealArithmetic> Neg for Rat<R> {
 impl<R: RealArithmetic> Add for Rat<R> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
+        let (left, right): (R, R) = cancel(rhs.d, self.d);
         Self::new(
-            self.n * rhs.d + rhs.n * self.d,
-            self.d * rhs.d
+            self.n * left + rhs.n * right,
+            self.d * left
         )
     }
 }
 impl<R: RealArithmetic> Sub for Rat<R> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
+        let (left, right): (R, R) = cancel(rhs.d, self.d);
         Self::new(
-            self.n * rhs.d - rhs.n * self.d,
-            self.d * rhs.d
+            self.n * left - rhs.n * right,
+            self.d * left
         )
     }
 }
 impl<R: RealArithmetic> Mul for Rat<R> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
-        Self::new(self.n * rhs.n, self.d * rhs.d)
+        let (n1, d2): (R, R) = cancel(self.n, rhs.d);
+        let (n2, d1): (R, R) = cancel(rhs.n, self.d);
+        Self::new(n1 * n2, d1 * d2)
     }
 }
 impl<R: RealArithmetic> Div for Rat<R> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
-        Self::new(self.n * rhs.d, self.d * rhs.n)
+        let (n1, n2): (R, R) = cancel(self.n, rhs.n);
+        let (d2, d1): (R, R) = cancel(rhs.d, self.d);
+        Self::new(n1 * d2, d1 * n2)
     }
 }
 impl<R: RealArithmetic> Rem for Rat<R> {
@@ -15,6 +15,7 @@ pub trait UsefulReals: RealArithmetic {
     const PI: Self;
     const HALFPI: Self;
     const QTRPI: Self;
+    fn settle(self) -> Self { self }
 }
 pub trait Inverse {
     fn inv(self) -> Self;
@@ -28,8 +29,8 @@ UsefulReals + Inverse + Identity
 {
     fn etothe(power: isize) -> Self {
         let mut running: Self = Self::ONE;
-        for _ in 0..power { running *= Self::E; }
-        for _ in power..0 { running *= Self::E; }
+        for _ in 0..power { running = (running * Self::E).settle(); }
+        for _ in power..0 { running = (running * Self::E).settle(); }
         if power < 0 { running.inv() } else { running }
     }
 }
@@ -39,37 +40,77 @@ pub trait MagSquare: Identity + Mul<Output = Self> {
 pub trait Magnitude: std::fmt::Debug + RealArithmetic + MagSquare + UsefulReals {
     fn rrt(self, error: Self) -> Self {
         let (mut t1, mut t2): (Self, Self) = (Self::SEED, Self::SEED + Self::ONE);
+        let target: Self = self.settle();
         let mut counter: usize = 0;
         while (t2 - t1).mag2() > error {
             if counter > 20 { break }; counter += 1;
             t1 = t2;
-            t2 -= (t2*t2 - self) / (Self::TWO * t2);
+            t2 = (t2 - (t2*t2 - target) / (Self::TWO * t2)).settle();
         }
         t2
     }
     fn mag1(self, error: Self) -> Self { self.mag2().rrt(error) }
 }
 
-pub trait RealArithmetic:
+pub trait Ring:
   Identity
-+ MagSquare
 + Copy
 + Neg<Output = Self>
 + PartialEq
-+ PartialOrd
 + Add<Output = Self>
 + Sub<Output = Self>
 + Mul<Output = Self>
-+ Div<Output = Self>
-+ Rem<Output = Self>
 + AddAssign
 + SubAssign
 + MulAssign
+{}
+pub trait Field:
+  Ring
++ Inverse
++ Div<Output = Self>
++ DivAssign
+{}
+pub trait RealArithmetic:
+  Ring
++ MagSquare
++ PartialOrd
++ Div<Output = Self>
++ Rem<Output = Self>
 + DivAssign
 + RemAssign
 {}
+pub trait CheckedArithmetic: RealArithmetic {
+    const MIN: Self;
+    const MAX: Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+pub trait Integer: CheckedArithmetic {
+    fn to_i128(self) -> i128;
+    fn from_i128(wide: i128) -> Option<Self>;
+}
+pub trait Promote: CheckedArithmetic {
+    type Wide: CheckedArithmetic;
+    fn promote(self) -> Self::Wide;
+    fn demote(wide: Self::Wide) -> Option<Self>;
+}
+// rational stand-ins for the UsefulReals constants, with numerators and
+// denominators no larger than BOUND, which keeps products of four of them in range
+pub trait RoundingPolicy: Integer + PowersOfTen + std::fmt::Debug {
+    const BOUND: Self;
+    const TWO: (Self, Self);
+    const E: (Self, Self);
+    const TAU: (Self, Self);
+    const PI: (Self, Self);
+    const HALFPI: (Self, Self);
+    const QTRPI: (Self, Self);
+}
 pub trait Reals: 
   RealArithmetic
++ Field
 + Inverse
 + Magnitude
 + PowersOfTen
@@ -96,6 +137,10 @@ impl Identity for u64 {
     const ZERO: Self = 0;
     const ONE: Self = 1;
 }
+impl Identity for u128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
 impl Identity for usize {
     const ZERO: Self = 0;
     const ONE: Self = 1;
@@ -116,6 +161,10 @@ impl Identity for i64 {
     const ZERO: Self = 0;
     const ONE: Self = 1;
 }
+impl Identity for i128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
 impl Identity for isize {
     const ZERO: Self = 0;
     const ONE: Self = 1;
@@ -133,18 +182,177 @@ impl MagSquare for i8 {}
 impl MagSquare for i16 {}
 impl MagSquare for i32 {}
 impl MagSquare for i64 {}
+impl MagSquare for i128 {}
 impl MagSquare for isize {}
 impl MagSquare for f32 {}
 impl MagSquare for f64 {}
 
+impl Ring for i8 {}
+impl Ring for i16 {}
+impl Ring for i32 {}
+impl Ring for i64 {}
+impl Ring for i128 {}
+impl Ring for isize {}
+impl Ring for f32 {}
+impl Ring for f64 {}
+
 impl RealArithmetic for i8 {}
 impl RealArithmetic for i16 {}
 impl RealArithmetic for i32 {}
 impl RealArithmetic for i64 {}
+impl RealArithmetic for i128 {}
 impl RealArithmetic for isize {}
 impl RealArithmetic for f32 {}
 impl RealArithmetic for f64 {}
 
+impl CheckedArithmetic for i8 {
+    const MIN: Self = i8::MIN;
+    const MAX: Self = i8::MAX;
+    fn checked_add(self, rhs: Self) -> Option<Self> { i8::checked_add(self, rhs) }
+    fn checked_sub(self, rhs: Self) -> Option<Self> { i8::checked_sub(self, rhs) }
+    fn checked_mul(self, rhs: Self) -> Option<Self> { i8::checked_mul(self, rhs) }
+    fn checked_div(self, rhs: Self) -> Option<Self> { i8::checked_div(self, rhs) }
+    fn checked_neg(self) -> Option<Self> { i8::checked_neg(self) }
+}
+impl CheckedArithmetic for i16 {
+    const MIN: Self = i16::MIN;
+    const MAX: Self = i16::MAX;
+    fn checked_add(self, rhs: Self) -> Option<Self> { i16::checked_add(self, rhs) }
+    fn checked_sub(self, rhs: Self) -> Option<Self> { i16::checked_sub(self, rhs) }
+    fn checked_mul(self, rhs: Self) -> Option<Self> { i16::checked_mul(self, rhs) }
+    fn checked_div(self, rhs: Self) -> Option<Self> { i16::checked_div(self, rhs) }
+    fn checked_neg(self) -> Option<Self> { i16::checked_neg(self) }
+}
+impl CheckedArithmetic for i32 {
+    const MIN: Self = i32::MIN;
+    const MAX: Self = i32::MAX;
+    fn checked_add(self, rhs: Self) -> Option<Self> { i32::checked_add(self, rhs) }
+    fn checked_sub(self, rhs: Self) -> Option<Self> { i32::checked_sub(self, rhs) }
+    fn checked_mul(self, rhs: Self) -> Option<Self> { i32::checked_mul(self, rhs) }
+    fn checked_div(self, rhs: Self) -> Option<Self> { i32::checked_div(self, rhs) }
+    fn checked_neg(self) -> Option<Self> { i32::checked_neg(self) }
+}
+impl CheckedArithmetic for i64 {
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
+    fn checked_add(self, rhs: Self) -> Option<Self> { i64::checked_add(self, rhs) }
+    fn checked_sub(self, rhs: Self) -> Option<Self> { i64::checked_sub(self, rhs) }
+    fn checked_mul(self, rhs: Self) -> Option<Self> { i64::checked_mul(self, rhs) }
+    fn checked_div(self, rhs: Self) -> Option<Self> { i64::checked_div(self, rhs) }
+    fn checked_neg(self) -> Option<Self> { i64::checked_neg(self) }
+}
+impl CheckedArithmetic for i128 {
+    const MIN: Self = i128::MIN;
+    const MAX: Self = i128::MAX;
+    fn checked_add(self, rhs: Self) -> Option<Self> { i128::checked_add(self, rhs) }
+    fn checked_sub(self, rhs: Self) -> Option<Self> { i128::checked_sub(self, rhs) }
+    fn checked_mul(self, rhs: Self) -> Option<Self> { i128::checked_mul(self, rhs) }
+    fn checked_div(self, rhs: Self) -> Option<Self> { i128::checked_div(self, rhs) }
+    fn checked_neg(self) -> Option<Self> { i128::checked_neg(self) }
+}
+impl CheckedArithmetic for isize {
+    const MIN: Self = isize::MIN;
+    const MAX: Self = isize::MAX;
+    fn checked_add(self, rhs: Self) -> Option<Self> { isize::checked_add(self, rhs) }
+    fn checked_sub(self, rhs: Self) -> Option<Self> { isize::checked_sub(self, rhs) }
+    fn checked_mul(self, rhs: Self) -> Option<Self> { isize::checked_mul(self, rhs) }
+    fn checked_div(self, rhs: Self) -> Option<Self> { isize::checked_div(self, rhs) }
+    fn checked_neg(self) -> Option<Self> { isize::checked_neg(self) }
+}
+
+impl Integer for i8 {
+    fn to_i128(self) -> i128 { self as i128 }
+    fn from_i128(wide: i128) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Integer for i16 {
+    fn to_i128(self) -> i128 { self as i128 }
+    fn from_i128(wide: i128) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Integer for i32 {
+    fn to_i128(self) -> i128 { self as i128 }
+    fn from_i128(wide: i128) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Integer for i64 {
+    fn to_i128(self) -> i128 { self as i128 }
+    fn from_i128(wide: i128) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Integer for i128 {
+    fn to_i128(self) -> i128 { self }
+    fn from_i128(wide: i128) -> Option<Self> { Some(wide) }
+}
+impl Integer for isize {
+    fn to_i128(self) -> i128 { self as i128 }
+    fn from_i128(wide: i128) -> Option<Self> { Self::try_from(wide).ok() }
+}
+
+impl Promote for i8 {
+    type Wide = i16;
+    fn promote(self) -> Self::Wide { self as i16 }
+    fn demote(wide: Self::Wide) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Promote for i16 {
+    type Wide = i32;
+    fn promote(self) -> Self::Wide { self as i32 }
+    fn demote(wide: Self::Wide) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Promote for i32 {
+    type Wide = i64;
+    fn promote(self) -> Self::Wide { self as i64 }
+    fn demote(wide: Self::Wide) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Promote for i64 {
+    type Wide = i128;
+    fn promote(self) -> Self::Wide { self as i128 }
+    fn demote(wide: Self::Wide) -> Option<Self> { Self::try_from(wide).ok() }
+}
+impl Promote for isize {
+    type Wide = i128;
+    fn promote(self) -> Self::Wide { self as i128 }
+    fn demote(wide: Self::Wide) -> Option<Self> { Self::try_from(wide).ok() }
+}
+
+impl RoundingPolicy for i32 {
+    const BOUND: Self = 1 << 7;
+    const TWO: (Self, Self) = (2, 1);
+    const E: (Self, Self) = (106, 39);
+    const TAU: (Self, Self) = (2 * Self::PI.0, Self::PI.1);
+    const PI: (Self, Self) = (22, 7);
+    const HALFPI: (Self, Self) = (Self::PI.0 / 2, Self::PI.1);
+    const QTRPI: (Self, Self) = (Self::PI.0 / 2, 2 * Self::PI.1);
+}
+impl RoundingPolicy for i64 {
+    const BOUND: Self = 1 << 15;
+    const TWO: (Self, Self) = (2, 1);
+    const E: (Self, Self) = (25946, 9545);
+    const TAU: (Self, Self) = (2 * Self::PI.0, Self::PI.1);
+    const PI: (Self, Self) = (355, 113);
+    const HALFPI: (Self, Self) = (Self::PI.0, 2 * Self::PI.1);
+    const QTRPI: (Self, Self) = (Self::PI.0, 4 * Self::PI.1);
+}
+impl RoundingPolicy for i128 {
+    const BOUND: Self = 1 << 31;
+    const TWO: (Self, Self) = (2, 1);
+    const E: (Self, Self) = (848456353, 312129649);
+    const TAU: (Self, Self) = (2 * Self::PI.0, Self::PI.1);
+    const PI: (Self, Self) = (1068966896, 340262731);
+    const HALFPI: (Self, Self) = (Self::PI.0 / 2, Self::PI.1);
+    const QTRPI: (Self, Self) = (Self::PI.0 / 4, Self::PI.1);
+}
+// isize takes the i64 constants on 64-bit targets and the i32 ones on narrower ones
+const WIDE_ISIZE: bool = isize::BITS >= 64;
+const fn isize_pair(wide: (i64, i64), narrow: (i32, i32)) -> (isize, isize) {
+    if WIDE_ISIZE { (wide.0 as isize, wide.1 as isize) } else { (narrow.0 as isize, narrow.1 as isize) }
+}
+impl RoundingPolicy for isize {
+    const BOUND: Self = if WIDE_ISIZE { i64::BOUND as isize } else { i32::BOUND as isize };
+    const TWO: (Self, Self) = isize_pair(i64::TWO, i32::TWO);
+    const E: (Self, Self) = isize_pair(i64::E, i32::E);
+    const TAU: (Self, Self) = isize_pair(i64::TAU, i32::TAU);
+    const PI: (Self, Self) = isize_pair(i64::PI, i32::PI);
+    const HALFPI: (Self, Self) = isize_pair(i64::HALFPI, i32::HALFPI);
+    const QTRPI: (Self, Self) = isize_pair(i64::QTRPI, i32::QTRPI);
+}
+
 impl PowersOfTen for u8 {
     fn order_of(power: isize) -> Self {
         if power < 0 { return 0 };
@@ -185,6 +393,16 @@ impl PowersOfTen for u64 {
         running
     }
 }
+impl PowersOfTen for u128 {
+    fn order_of(power: isize) -> Self {
+        if power < 0 { return 0 };
+        let mut running: Self = 1;
+        for _ in 0..power as usize {
+            running *= 10;
+        }
+        running
+    }
+}
 impl PowersOfTen for usize {
     fn order_of(power: isize) -> Self {
         if power < 0 { return 0 };
@@ -235,6 +453,16 @@ impl PowersOfTen for i64 {
         running
     }
 }
+impl PowersOfTen for i128 {
+    fn order_of(power: isize) -> Self {
+        if power < 0 { return 0 };
+        let mut running: Self = 1;
+        for _ in 0..power as usize {
+            running *= 10;
+        }
+        running
+    }
+}
 impl PowersOfTen for isize {
     fn order_of(power: isize) -> Self {
         if power < 0 { return 0 };
@@ -290,6 +518,8 @@ impl Inverse for f32 {
 impl Inverse for f64 {
     fn inv(self) -> Self { 1.0 / self }
 }
+impl Field for f32 {}
+impl Field for f64 {}
 impl PowersOfE for f32 {}
 impl PowersOfE for f64 {}
 impl Magnitude for f32 {}
@@ -312,11 +542,13 @@ impl LaTeX for u8 {}
 impl LaTeX for u16 {}
 impl LaTeX for u32 {}
 impl LaTeX for u64 {}
+impl LaTeX for u128 {}
 impl LaTeX for usize {}
 impl LaTeX for i8 {}
 impl LaTeX for i16 {}
 impl LaTeX for i32 {}
 impl LaTeX for i64 {}
+impl LaTeX for i128 {}
 impl LaTeX for isize {}
 
 impl LaTeX for f32 {}
@@ -0,0 +1,259 @@
+use std::ops::{
+    Neg, Add, Sub, Mul, Div,
+    AddAssign, SubAssign, MulAssign, DivAssign};
+use std::cmp::{PartialEq, PartialOrd, Ordering};
+use std::marker::PhantomData;
+use crate::rules::*;
+use std::fmt;
+
+// exponents of metre, kilogram, second, ampere, kelvin, mole and candela
+pub trait Dimension: Copy + fmt::Debug {
+    const EXPONENTS: [i32; 7];
+}
+const SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+const fn same(lhs: [i32; 7], rhs: [i32; 7]) -> bool {
+    let mut indx: usize = 0;
+    while indx < 7 {
+        if lhs[indx] != rhs[indx] { return false };
+        indx += 1;
+    }
+    true
+}
+const fn combine(lhs: [i32; 7], rhs: [i32; 7], sign: i32) -> [i32; 7] {
+    let mut result: [i32; 7] = lhs;
+    let mut indx: usize = 0;
+    while indx < 7 {
+        result[indx] += sign * rhs[indx];
+        indx += 1;
+    }
+    result
+}
+const fn halve(exponents: [i32; 7]) -> [i32; 7] {
+    let mut result: [i32; 7] = exponents;
+    let mut indx: usize = 0;
+    while indx < 7 {
+        if exponents[indx] % 2 != 0 { panic!("square root of a dimension with an odd exponent") };
+        result[indx] /= 2;
+        indx += 1;
+    }
+    result
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Dimensionless;
+#[derive(Clone, Copy, Debug)]
+pub struct Length;
+#[derive(Clone, Copy, Debug)]
+pub struct Mass;
+#[derive(Clone, Copy, Debug)]
+pub struct Time;
+#[derive(Clone, Copy, Debug)]
+pub struct Current;
+#[derive(Clone, Copy, Debug)]
+pub struct Temperature;
+#[derive(Clone, Copy, Debug)]
+pub struct Amount;
+#[derive(Clone, Copy, Debug)]
+pub struct Luminosity;
+#[derive(Clone, Copy, Debug)]
+pub struct Product<A: Dimension, B: Dimension>(PhantomData<(A, B)>);
+#[derive(Clone, Copy, Debug)]
+pub struct Quotient<A: Dimension, B: Dimension>(PhantomData<(A, B)>);
+#[derive(Clone, Copy, Debug)]
+pub struct Root<A: Dimension>(PhantomData<A>);
+
+pub type Area = Product<Length, Length>;
+pub type Velocity = Quotient<Length, Time>;
+pub type Acceleration = Quotient<Velocity, Time>;
+pub type Force = Product<Mass, Acceleration>;
+pub type Energy = Product<Force, Length>;
+
+impl Dimension for Dimensionless {
+    const EXPONENTS: [i32; 7] = [0, 0, 0, 0, 0, 0, 0];
+}
+impl Dimension for Length {
+    const EXPONENTS: [i32; 7] = [1, 0, 0, 0, 0, 0, 0];
+}
+impl Dimension for Mass {
+    const EXPONENTS: [i32; 7] = [0, 1, 0, 0, 0, 0, 0];
+}
+impl Dimension for Time {
+    const EXPONENTS: [i32; 7] = [0, 0, 1, 0, 0, 0, 0];
+}
+impl Dimension for Current {
+    const EXPONENTS: [i32; 7] = [0, 0, 0, 1, 0, 0, 0];
+}
+impl Dimension for Temperature {
+    const EXPONENTS: [i32; 7] = [0, 0, 0, 0, 1, 0, 0];
+}
+impl Dimension for Amount {
+    const EXPONENTS: [i32; 7] = [0, 0, 0, 0, 0, 1, 0];
+}
+impl Dimension for Luminosity {
+    const EXPONENTS: [i32; 7] = [0, 0, 0, 0, 0, 0, 1];
+}
+impl<A: Dimension, B: Dimension> Dimension for Product<A, B> {
+    const EXPONENTS: [i32; 7] = combine(A::EXPONENTS, B::EXPONENTS, 1);
+}
+impl<A: Dimension, B: Dimension> Dimension for Quotient<A, B> {
+    const EXPONENTS: [i32; 7] = combine(A::EXPONENTS, B::EXPONENTS, -1);
+}
+impl<A: Dimension> Dimension for Root<A> {
+    const EXPONENTS: [i32; 7] = halve(A::EXPONENTS);
+}
+
+// dimensions are compared by exponents, so Product<Length, Time> and Product<Time, Length>
+// mix freely, while mismatched sums fail when the compiler evaluates the assertion;
+// that happens during code generation, so cargo check alone does not report them
+/// ```
+/// use basemath::units::*;
+/// let area: Quantity<f64, Area> = Quantity::new(6.0);
+/// let swapped: Quantity<f64, Product<Length, Length>> = Quantity::new(1.0);
+/// assert_eq!(area + swapped, Quantity::<f64, Area>::new(7.0));
+/// assert!(area - swapped == swapped * 5.0);
+/// let energy: Quantity<f64, Energy> = (Quantity::<f64, Force>::new(2.0) * Quantity::<f64, Length>::new(3.0)).cast();
+/// assert_eq!(energy.value, 6.0);
+/// ```
+/// ```compile_fail
+/// use basemath::units::*;
+/// let _ = Quantity::<f64, Length>::new(1.0) + Quantity::<f64, Time>::new(1.0);
+/// ```
+/// ```compile_fail
+/// use basemath::units::*;
+/// let _ = Quantity::<f64, Mass>::new(1.0) - Quantity::<f64, Current>::new(1.0);
+/// ```
+/// ```compile_fail
+/// use basemath::units::*;
+/// let _ = Quantity::<f64, Velocity>::new(1.0) == Quantity::<f64, Acceleration>::new(1.0);
+/// ```
+/// ```compile_fail
+/// use basemath::units::*;
+/// let _: Quantity<f64, Energy> = Quantity::<f64, Force>::new(1.0).cast();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Quantity<T: RealArithmetic, D: Dimension> {
+    pub value: T,
+    dim: PhantomData<D>,
+}
+impl<T: RealArithmetic, D: Dimension> Quantity<T, D> {
+    pub fn new(value: T) -> Self {
+        Self { value, dim: PhantomData }
+    }
+    pub fn exponents() -> [i32; 7] {
+        D::EXPONENTS
+    }
+    // reinterprets the quantity under an equivalent dimension type
+    pub fn cast<E: Dimension>(self) -> Quantity<T, E> {
+        const { assert!(same(D::EXPONENTS, E::EXPONENTS), "mismatched dimensions") };
+        Quantity::new(self.value)
+    }
+    fn written(&self, latex: bool) -> String {
+        let mut units: Vec<String> = Vec::new();
+        for (symbol, power) in SYMBOLS.iter().zip(D::EXPONENTS) {
+            units.push(match (latex, power) {
+                (_, 0) => continue,
+                (false, 1) => symbol.to_string(),
+                (false, _) => format!("{}^{}", symbol, power),
+                (true, 1) => format!("\\mathrm{{{}}}", symbol),
+                (true, _) => format!("\\mathrm{{{}}}^{{{}}}", symbol, power),
+            });
+        }
+        if units.is_empty() { return String::new() };
+        if latex { format!("\\,{}", units.join("\\,")) } else { format!(" {}", units.join(" ")) }
+    }
+}
+impl<T: Magnitude, D: Dimension> Quantity<T, D> {
+    pub fn rrt(self, error: T) -> Quantity<T, Root<D>> {
+        Quantity::new(self.value.rrt(error))
+    }
+}
+
+impl<T: RealArithmetic + fmt::Display, D: Dimension> fmt::Display for Quantity<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.written(false))
+    }
+}
+impl<T: RealArithmetic + LaTeX, D: Dimension> LaTeX for Quantity<T, D> {
+    fn latex(&self) -> String {
+        format!("{}{}", self.value.latex(), self.written(true))
+    }
+}
+
+impl<T: RealArithmetic, A: Dimension, B: Dimension> PartialEq<Quantity<T, B>> for Quantity<T, A> {
+    fn eq(&self, rhs: &Quantity<T, B>) -> bool {
+        const { assert!(same(A::EXPONENTS, B::EXPONENTS), "mismatched dimensions") };
+        self.value == rhs.value
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> PartialOrd<Quantity<T, B>> for Quantity<T, A> {
+    fn partial_cmp(&self, rhs: &Quantity<T, B>) -> Option<Ordering> {
+        const { assert!(same(A::EXPONENTS, B::EXPONENTS), "mismatched dimensions") };
+        self.value.partial_cmp(&rhs.value)
+    }
+}
+
+impl<T: RealArithmetic, D: Dimension> Neg for Quantity<T, D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.value)
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> Add<Quantity<T, B>> for Quantity<T, A> {
+    type Output = Self;
+    fn add(self, rhs: Quantity<T, B>) -> Self {
+        const { assert!(same(A::EXPONENTS, B::EXPONENTS), "mismatched dimensions") };
+        Self::new(self.value + rhs.value)
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> Sub<Quantity<T, B>> for Quantity<T, A> {
+    type Output = Self;
+    fn sub(self, rhs: Quantity<T, B>) -> Self {
+        const { assert!(same(A::EXPONENTS, B::EXPONENTS), "mismatched dimensions") };
+        Self::new(self.value - rhs.value)
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> Mul<Quantity<T, B>> for Quantity<T, A> {
+    type Output = Quantity<T, Product<A, B>>;
+    fn mul(self, rhs: Quantity<T, B>) -> Quantity<T, Product<A, B>> {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> Div<Quantity<T, B>> for Quantity<T, A> {
+    type Output = Quantity<T, Quotient<A, B>>;
+    fn div(self, rhs: Quantity<T, B>) -> Quantity<T, Quotient<A, B>> {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+impl<T: RealArithmetic, D: Dimension> Mul<T> for Quantity<T, D> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.value * rhs)
+    }
+}
+impl<T: RealArithmetic, D: Dimension> Div<T> for Quantity<T, D> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.value / rhs)
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> AddAssign<Quantity<T, B>> for Quantity<T, A> {
+    fn add_assign(&mut self, rhs: Quantity<T, B>) {
+        *self = *self + rhs;
+    }
+}
+impl<T: RealArithmetic, A: Dimension, B: Dimension> SubAssign<Quantity<T, B>> for Quantity<T, A> {
+    fn sub_assign(&mut self, rhs: Quantity<T, B>) {
+        *self = *self - rhs;
+    }
+}
+impl<T: RealArithmetic, D: Dimension> MulAssign<T> for Quantity<T, D> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+impl<T: RealArithmetic, D: Dimension> DivAssign<T> for Quantity<T, D> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
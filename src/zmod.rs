@@ -0,0 +1,165 @@
+use std::ops::{
+    Neg, Add, Sub, Mul, Div,
+    AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::rules::*;
+use crate::nt::is_prime;
+use std::fmt;
+
+/// ```
+/// use basemath::{rules::*, zmod::Zmod};
+/// assert_eq!(Zmod::<7>::new(3).inv(), Zmod::new(5));
+/// assert_eq!(Zmod::<6>::new(5).try_inv(), Some(Zmod::new(5)));
+/// ```
+/// ```compile_fail
+/// use basemath::zmod::Zmod;
+/// let _ = Zmod::<6>::new(5) / Zmod::new(5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zmod<const N: u64> {
+    pub v: u64,
+}
+impl<const N: u64> Zmod<N> {
+    pub fn raw(v: u64) -> Self {
+        Self { v: v % N }
+    }
+    pub fn new(v: i64) -> Self {
+        Self { v: (v as i128).rem_euclid(N as i128) as u64 }
+    }
+    pub fn modulus() -> u64 { N }
+    pub fn is_field() -> bool {
+        is_prime(N as i128)
+    }
+    pub fn pow(self, power: u64) -> Self {
+        let (mut base, mut power): (Self, u64) = (self, power);
+        let mut running: Self = Self::ONE;
+        while power > 0 {
+            if power & 1 == 1 { running *= base; }
+            base *= base;
+            power >>= 1;
+        }
+        running
+    }
+    pub fn try_inv(self) -> Option<Self> {
+        let (mut r0, mut r1): (i128, i128) = (N as i128, self.v as i128);
+        let (mut t0, mut t1): (i128, i128) = (0, 1);
+        while r1 != 0 {
+            let q: i128 = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        if r0 != 1 { return None };
+        Some(Self { v: t0.rem_euclid(N as i128) as u64 })
+    }
+}
+
+impl<const N: u64> Identity for Zmod<N> {
+    const ZERO: Self = Self { v: 0 };
+    const ONE: Self = Self { v: 1 % N };
+}
+impl<const N: u64> Inverse for Zmod<N> {
+    fn inv(self) -> Self {
+        const { assert!(prime_modulus(N), "inverses modulo a composite N need try_inv") };
+        match self.try_inv() {
+            Some(inverse) => inverse,
+            None => panic!("{} has no inverse modulo {}", self.v, N),
+        }
+    }
+}
+impl<const N: u64> Ring for Zmod<N> {}
+// only a field when N is prime, so inv and division, and with them any use as a Field,
+// fail to compile for composite N; try_inv still works there
+impl<const N: u64> Field for Zmod<N> {}
+
+// deterministic Miller-Rabin for 64 bits, in a form the compiler can evaluate
+pub(crate) const fn prime_modulus(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 { return false };
+    let mut indx: usize = 0;
+    while indx < BASES.len() {
+        if n.is_multiple_of(BASES[indx]) { return n == BASES[indx] };
+        indx += 1;
+    }
+    let (mut odd, mut twos): (u64, u32) = (n - 1, 0);
+    while odd.is_multiple_of(2) { odd /= 2; twos += 1; }
+    indx = 0;
+    while indx < BASES.len() {
+        let (mut base, mut exp, mut x): (u128, u64, u128) = (BASES[indx] as u128, odd, 1);
+        while exp > 0 {
+            if exp & 1 == 1 { x = x * base % n as u128; }
+            base = base * base % n as u128;
+            exp >>= 1;
+        }
+        let mut passed: bool = x == 1 || x == n as u128 - 1;
+        let mut round: u32 = 1;
+        while !passed && round < twos {
+            x = x * x % n as u128;
+            passed = x == n as u128 - 1;
+            round += 1;
+        }
+        if !passed { return false };
+        indx += 1;
+    }
+    true
+}
+
+impl<const N: u64> fmt::Display for Zmod<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.v, N)
+    }
+}
+impl<const N: u64> LaTeX for Zmod<N> {
+    fn latex(&self) -> String {
+        format!("{} \\pmod{{{}}}", self.v, N)
+    }
+}
+
+impl<const N: u64> Neg for Zmod<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if self.v == 0 { self } else { Self { v: N - self.v } }
+    }
+}
+impl<const N: u64> Add for Zmod<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self { v: ((self.v as u128 + rhs.v as u128) % N as u128) as u64 }
+    }
+}
+impl<const N: u64> Sub for Zmod<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+impl<const N: u64> Mul for Zmod<N> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self { v: ((self.v as u128 * rhs.v as u128) % N as u128) as u64 }
+    }
+}
+impl<const N: u64> Div for Zmod<N> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Mul::mul(self, rhs.inv())
+    }
+}
+impl<const N: u64> AddAssign for Zmod<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<const N: u64> SubAssign for Zmod<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<const N: u64> MulAssign for Zmod<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<const N: u64> DivAssign for Zmod<N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}